@@ -11,7 +11,9 @@ use spl_associated_token_account::{
 };
 use spl_token_2022::extension::{ExtensionType, StateWithExtensionsOwned};
 
-use crate::resolver::{DELETE_SLASH_PROPOSAL_DURATION, VETO_DURATION};
+use crate::resolver::{
+    DELEGATION_AMOUNT, DELETE_SLASH_PROPOSAL_DURATION, MINT_AMOUNT, VETO_DURATION,
+};
 
 use super::{
     resolver_client::{ResolverProgramClient, SlasherRoot},
@@ -40,6 +42,8 @@ pub struct ConfiguredVault {
     pub vault_root: VaultRoot,
     #[allow(dead_code)]
     pub restaking_config_admin: Keypair,
+    #[allow(dead_code)]
+    pub resolver_config_admin: Keypair,
     pub ncn_root: NcnRoot,
     pub operator_roots: Vec<OperatorRoot>,
     #[allow(dead_code)]
@@ -311,11 +315,52 @@ impl TestBuilder {
         Ok(())
     }
 
+    pub fn warp_to_slot(&mut self, slot: u64) -> Result<(), BanksClientError> {
+        self.context
+            .warp_to_slot(slot)
+            .map_err(|_| BanksClientError::ClientError("failed to warp slot"))
+    }
+
     pub async fn get_current_slot(&mut self) -> Result<u64, BanksClientError> {
         let clock: Clock = self.context.banks_client.get_sysvar().await?;
         Ok(clock.slot)
     }
 
+    pub async fn get_account_data_len(
+        &mut self,
+        address: &Pubkey,
+    ) -> Result<usize, BanksClientError> {
+        let account = self
+            .context
+            .banks_client
+            .get_account(*address)
+            .await?
+            .ok_or(BanksClientError::ClientError("failed to read account"))?;
+        Ok(account.data.len())
+    }
+
+    /// Truncates an account's data to `len` bytes, standing in for an account created with an
+    /// older, smaller layout
+    pub async fn truncate_account(
+        &mut self,
+        address: &Pubkey,
+        len: usize,
+    ) -> Result<(), BanksClientError> {
+        let mut account = self
+            .context
+            .banks_client
+            .get_account(*address)
+            .await?
+            .ok_or(BanksClientError::ClientError("failed to read account"))?;
+        let rent = self.context.banks_client.get_rent().await?;
+
+        account.data.truncate(len);
+        account.lamports = rent.minimum_balance(len);
+        self.context.set_account(address, &account.into());
+
+        Ok(())
+    }
+
     /// Configures a vault with an NCN and operators fully configured
     pub async fn setup_vault_with_ncn_and_operators(
         &mut self,
@@ -386,7 +431,25 @@ impl TestBuilder {
             operator_roots.push(operator_root);
         }
 
-        resolver_program_client
+        // Back each operator with stake so slashes can be proposed against it
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await?;
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await?;
+        for operator_root in operator_roots.iter() {
+            vault_program_client
+                .do_add_delegation(
+                    &vault_root,
+                    &operator_root.operator_pubkey,
+                    DELEGATION_AMOUNT,
+                )
+                .await?;
+        }
+
+        let resolver_config_admin = resolver_program_client
             .do_initialize_config()
             .await
             .unwrap();
@@ -402,6 +465,21 @@ impl TestBuilder {
             .await
             .unwrap();
 
+        resolver_program_client
+            .do_initialize_ncn_slash_stats(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        for operator_root in operator_roots.iter() {
+            resolver_program_client
+                .do_initialize_slash_proposal_list(
+                    &ncn_root.ncn_pubkey,
+                    &operator_root.operator_pubkey,
+                )
+                .await
+                .unwrap();
+        }
+
         let mut slashers_amounts: Vec<(SlasherRoot, u64)> =
             Vec::with_capacity(slasher_amounts.len());
         for amount in slasher_amounts {
@@ -456,9 +534,47 @@ impl TestBuilder {
             vault_root,
             vault_config_admin,
             restaking_config_admin,
+            resolver_config_admin,
             ncn_root,
             operator_roots,
             slashers_amounts,
         })
     }
+
+    /// Configures a second vault that delegates `amount` to the operator but has no tickets with
+    /// any NCN or slasher
+    pub async fn setup_unregistered_vault_for_operator(
+        &mut self,
+        operator_root: &OperatorRoot,
+        amount: u64,
+    ) -> TestResult<VaultRoot> {
+        let mut vault_program_client = self.vault_program_client();
+        let mut restaking_program_client = self.restaking_program_client();
+
+        let vault_root = vault_program_client.do_initialize_vault(0, 0, 0, 9).await?;
+
+        restaking_program_client
+            .do_initialize_operator_vault_ticket(operator_root, &vault_root.vault_pubkey)
+            .await?;
+        self.warp_slot_incremental(1).await.unwrap();
+        restaking_program_client
+            .do_warmup_operator_vault_ticket(operator_root, &vault_root.vault_pubkey)
+            .await?;
+        vault_program_client
+            .do_initialize_vault_operator_delegation(&vault_root, &operator_root.operator_pubkey)
+            .await?;
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), amount)
+            .await?;
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, amount, amount)
+            .await?;
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator_root.operator_pubkey, amount)
+            .await?;
+
+        Ok(vault_root)
+    }
 }
@@ -18,6 +18,8 @@ pub enum TestError {
     ProgramError(#[from] ProgramError),
     #[error("Account not Found")]
     AccountNotFound,
+    #[error("Return data not Found")]
+    ReturnDataNotFound,
 }
 
 impl TestError {
@@ -29,7 +31,9 @@ impl TestError {
                 BanksClientError::SimulationError { err, .. } => Some(err.clone()),
                 _ => None,
             },
-            TestError::ProgramError(_) | TestError::AccountNotFound => None,
+            TestError::ProgramError(_)
+            | TestError::AccountNotFound
+            | TestError::ReturnDataNotFound => None,
         }
     }
 }
@@ -1,3 +1,4 @@
+use borsh::BorshDeserialize;
 use jito_bytemuck::AccountDeserialize;
 use jito_restaking_core::{
     ncn_operator_state::NcnOperatorState, ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
@@ -10,15 +11,21 @@ use jito_vault_core::{
 };
 use resolver_core::{
     config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
-    ncn_slash_proposal_ticket::NcnSlashProposalTicket, resolver::Resolver,
-    slash_proposal::SlashProposal, slasher::Slasher,
+    ncn_slash_proposal_ticket::NcnSlashProposalTicket, ncn_slash_stats::NcnSlashStats,
+    resolver::Resolver, slash_proposal::SlashProposal, slash_proposal_list::SlashProposalList,
+    slasher::Slasher,
+};
+use resolver_sdk::{
+    error::ResolverError,
+    instruction::SlasherAdminRole,
+    return_data::{ConfigStatus, ProposedSlash, SlashProposalStatusData},
+    sdk::{BatchSlashOperator, BatchVetoProposal},
 };
-use resolver_sdk::{error::ResolverError, instruction::SlasherAdminRole};
 use solana_program::{
     clock::Clock, instruction::InstructionError, native_token::sol_to_lamports, pubkey::Pubkey,
     system_instruction::transfer,
 };
-use solana_program_test::BanksClient;
+use solana_program_test::{BanksClient, BanksClientError};
 use solana_sdk::{
     commitment_config::CommitmentLevel,
     signature::Keypair,
@@ -41,6 +48,19 @@ pub struct SlasherRoot {
     pub slasher_admin: Keypair,
 }
 
+/// Parameters for `SetNcnResolverProgramConfigParams`, fields left as `None` are unchanged
+#[derive(Debug, Default)]
+pub struct NcnResolverProgramConfigParams {
+    pub resolver_change_delay: Option<u64>,
+    pub resolver_share_bps: Option<u16>,
+    pub treasury: Option<Pubkey>,
+    pub veto_extension_slots: Option<u64>,
+    pub max_veto_extensions: Option<u64>,
+    pub approval_threshold: Option<u64>,
+    pub execution_delay_slots: Option<u64>,
+    pub re_veto_window_slots: Option<u64>,
+}
+
 pub struct ResolverProgramClient {
     banks_client: BanksClient,
     payer: Keypair,
@@ -79,6 +99,70 @@ impl ResolverProgramClient {
         Ok(T::try_from_slice_unchecked(&mut account.data.as_slice())?.clone())
     }
 
+    /// Simulates `GetConfigStatus` for the NCN and decodes its return data
+    pub async fn get_config_status(&mut self, ncn: &Pubkey) -> TestResult<ConfigStatus> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let result = self
+            .banks_client
+            .simulate_transaction(Transaction::new_signed_with_payer(
+                &[resolver_sdk::sdk::get_config_status(
+                    &resolver_program::id(),
+                    &Config::find_program_address(&resolver_program::id()).0,
+                    &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                    ncn,
+                )],
+                Some(&self.payer.pubkey()),
+                &[&self.payer],
+                blockhash,
+            ))
+            .await?;
+
+        let return_data = result
+            .simulation_details
+            .and_then(|details| details.return_data)
+            .ok_or(TestError::ReturnDataNotFound)?;
+        ConfigStatus::try_from_slice(&return_data.data).map_err(|_| TestError::ReturnDataNotFound)
+    }
+
+    /// Simulates `GetSlashProposalStatus` for the slash proposal and decodes its return data
+    pub async fn get_slash_proposal_status(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher_root: &SlasherRoot,
+    ) -> TestResult<SlashProposalStatusData> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let result = self
+            .banks_client
+            .simulate_transaction(Transaction::new_signed_with_payer(
+                &[resolver_sdk::sdk::get_slash_proposal_status(
+                    &resolver_program::id(),
+                    &Config::find_program_address(&resolver_program::id()).0,
+                    ncn,
+                    operator,
+                    &slasher_root.slasher_pubkey,
+                    &SlashProposal::find_program_address(
+                        &resolver_program::id(),
+                        ncn,
+                        operator,
+                        &slasher_root.slasher_pubkey,
+                    )
+                    .0,
+                )],
+                Some(&self.payer.pubkey()),
+                &[&self.payer],
+                blockhash,
+            ))
+            .await?;
+
+        let return_data = result
+            .simulation_details
+            .and_then(|details| details.return_data)
+            .ok_or(TestError::ReturnDataNotFound)?;
+        SlashProposalStatusData::try_from_slice(&return_data.data)
+            .map_err(|_| TestError::ReturnDataNotFound)
+    }
+
     pub async fn do_initialize_config(&mut self) -> TestResult<Keypair> {
         let resolver_config_pubkey = Config::find_program_address(&resolver_program::id()).0;
         let resolver_config_admin = Keypair::new();
@@ -185,7 +269,7 @@ impl ResolverProgramClient {
         })
     }
 
-    async fn initialize_resolver(
+    pub async fn initialize_resolver(
         &mut self,
         ncn: &Pubkey,
         resolver: &Pubkey,
@@ -264,8 +348,29 @@ impl ResolverProgramClient {
         ncn: &Pubkey,
         operator: &Pubkey,
         slasher_root: &SlasherRoot,
+        vault: &Pubkey,
         slash_amount: u64,
-    ) -> TestResult<()> {
+    ) -> TestResult<ProposedSlash> {
+        self.do_propose_slash_with_destination(
+            ncn,
+            operator,
+            slasher_root,
+            vault,
+            slash_amount,
+            Pubkey::default(),
+        )
+        .await
+    }
+
+    pub async fn do_propose_slash_with_destination(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher_root: &SlasherRoot,
+        vault: &Pubkey,
+        slash_amount: u64,
+        slash_destination: Pubkey,
+    ) -> TestResult<ProposedSlash> {
         // create resolver + add operator vault
         let slash_proposal = SlashProposal::find_program_address(
             &resolver_program::id(),
@@ -280,6 +385,14 @@ impl ResolverProgramClient {
             &slash_proposal,
         )
         .0;
+        let slash_proposal_list =
+            SlashProposalList::find_program_address(&resolver_program::id(), ncn, operator).0;
+        let vault_operator_delegation = VaultOperatorDelegation::find_program_address(
+            &jito_vault_program::id(),
+            vault,
+            operator,
+        )
+        .0;
 
         self.propose_slash(
             ncn,
@@ -287,12 +400,17 @@ impl ResolverProgramClient {
             &slasher_root.slasher_pubkey,
             &slash_proposal,
             &ncn_slash_proposal_ticket,
+            &slash_proposal_list,
             &slasher_root.slasher_admin,
+            vault,
+            &vault_operator_delegation,
             slash_amount,
+            slash_destination,
         )
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn propose_slash(
         &mut self,
         ncn: &Pubkey,
@@ -300,13 +418,216 @@ impl ResolverProgramClient {
         slasher: &Pubkey,
         slash_proposal: &Pubkey,
         ncn_slash_proposal_ticket: &Pubkey,
+        slash_proposal_list: &Pubkey,
+        slasher_admin: &Keypair,
+        vault: &Pubkey,
+        vault_operator_delegation: &Pubkey,
+        slash_amount: u64,
+        slash_destination: Pubkey,
+    ) -> TestResult<ProposedSlash> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        let result = self
+            .banks_client
+            .process_transaction_with_metadata(Transaction::new_signed_with_payer(
+                &[resolver_sdk::sdk::propose_slash(
+                    &resolver_program::id(),
+                    &Config::find_program_address(&resolver_program::id()).0,
+                    &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                    ncn,
+                    operator,
+                    slasher,
+                    slash_proposal,
+                    ncn_slash_proposal_ticket,
+                    slash_proposal_list,
+                    &NcnSlashStats::find_program_address(&resolver_program::id(), ncn).0,
+                    &slasher_admin.pubkey(),
+                    vault,
+                    &NcnVaultSlasherTicket::find_program_address(
+                        &jito_restaking_program::id(),
+                        ncn,
+                        vault,
+                        slasher,
+                    )
+                    .0,
+                    vault_operator_delegation,
+                    slash_amount,
+                    slash_destination,
+                )],
+                Some(&slasher_admin.pubkey()),
+                &[slasher_admin],
+                blockhash,
+            ))
+            .await?;
+        result
+            .result
+            .map_err(|e| TestError::BanksClientError(BanksClientError::TransactionError(e)))?;
+
+        let return_data = result
+            .metadata
+            .and_then(|metadata| metadata.return_data)
+            .ok_or(TestError::ReturnDataNotFound)?;
+        ProposedSlash::from_return_data(&return_data.data)
+            .map_err(|_| TestError::ReturnDataNotFound)
+    }
+
+    pub async fn do_propose_slash_bps(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher_root: &SlasherRoot,
+        vault: &Pubkey,
+        slash_bps: u16,
+    ) -> TestResult<()> {
+        let slash_proposal = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            ncn,
+            operator,
+            &slasher_root.slasher_pubkey,
+        )
+        .0;
+        let ncn_slash_proposal_ticket = NcnSlashProposalTicket::find_program_address(
+            &resolver_program::id(),
+            ncn,
+            &slash_proposal,
+        )
+        .0;
+        let slash_proposal_list =
+            SlashProposalList::find_program_address(&resolver_program::id(), ncn, operator).0;
+        let vault_operator_delegation = VaultOperatorDelegation::find_program_address(
+            &jito_vault_program::id(),
+            vault,
+            operator,
+        )
+        .0;
+
+        self.propose_slash_bps(
+            ncn,
+            operator,
+            &slasher_root.slasher_pubkey,
+            &slash_proposal,
+            &ncn_slash_proposal_ticket,
+            &slash_proposal_list,
+            &slasher_root.slasher_admin,
+            vault,
+            &vault_operator_delegation,
+            slash_bps,
+        )
+        .await
+    }
+
+    /// Proposes a batch slash against `operators`, given as (operator, weight) pairs
+    pub async fn do_propose_slash_batch(
+        &mut self,
+        ncn: &Pubkey,
+        slasher_root: &SlasherRoot,
+        vault: &Pubkey,
+        operators: &[(Pubkey, u64)],
+        slash_amount: u64,
+    ) -> TestResult<()> {
+        let operators: Vec<BatchSlashOperator> = operators
+            .iter()
+            .map(|(operator, weight)| {
+                let slash_proposal = SlashProposal::find_program_address(
+                    &resolver_program::id(),
+                    ncn,
+                    operator,
+                    &slasher_root.slasher_pubkey,
+                )
+                .0;
+                BatchSlashOperator {
+                    operator: *operator,
+                    slash_proposal,
+                    ncn_slash_proposal_ticket: NcnSlashProposalTicket::find_program_address(
+                        &resolver_program::id(),
+                        ncn,
+                        &slash_proposal,
+                    )
+                    .0,
+                    slash_proposal_list: SlashProposalList::find_program_address(
+                        &resolver_program::id(),
+                        ncn,
+                        operator,
+                    )
+                    .0,
+                    vault_operator_delegation: VaultOperatorDelegation::find_program_address(
+                        &jito_vault_program::id(),
+                        vault,
+                        operator,
+                    )
+                    .0,
+                    weight: *weight,
+                }
+            })
+            .collect();
+
+        self.propose_slash_batch(
+            ncn,
+            &slasher_root.slasher_pubkey,
+            &slasher_root.slasher_admin,
+            vault,
+            &operators,
+            slash_amount,
+        )
+        .await
+    }
+
+    async fn propose_slash_batch(
+        &mut self,
+        ncn: &Pubkey,
+        slasher: &Pubkey,
         slasher_admin: &Keypair,
+        vault: &Pubkey,
+        operators: &[BatchSlashOperator],
         slash_amount: u64,
     ) -> TestResult<()> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
         self.process_transaction(&Transaction::new_signed_with_payer(
-            &[resolver_sdk::sdk::propose_slash(
+            &[resolver_sdk::sdk::propose_slash_batch(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                ncn,
+                slasher,
+                &slasher_admin.pubkey(),
+                &NcnSlashStats::find_program_address(&resolver_program::id(), ncn).0,
+                vault,
+                &NcnVaultSlasherTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    ncn,
+                    vault,
+                    slasher,
+                )
+                .0,
+                operators,
+                slash_amount,
+            )],
+            Some(&slasher_admin.pubkey()),
+            &[slasher_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn propose_slash_bps(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher: &Pubkey,
+        slash_proposal: &Pubkey,
+        ncn_slash_proposal_ticket: &Pubkey,
+        slash_proposal_list: &Pubkey,
+        slasher_admin: &Keypair,
+        vault: &Pubkey,
+        vault_operator_delegation: &Pubkey,
+        slash_bps: u16,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::propose_slash_bps(
                 &resolver_program::id(),
                 &Config::find_program_address(&resolver_program::id()).0,
                 &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
@@ -315,8 +636,19 @@ impl ResolverProgramClient {
                 slasher,
                 slash_proposal,
                 ncn_slash_proposal_ticket,
+                slash_proposal_list,
+                &NcnSlashStats::find_program_address(&resolver_program::id(), ncn).0,
                 &slasher_admin.pubkey(),
-                slash_amount,
+                vault,
+                &NcnVaultSlasherTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    ncn,
+                    vault,
+                    slasher,
+                )
+                .0,
+                vault_operator_delegation,
+                slash_bps,
             )],
             Some(&slasher_admin.pubkey()),
             &[slasher_admin],
@@ -330,7 +662,7 @@ impl ResolverProgramClient {
         ncn_root: &NcnRoot,
         operator: &Pubkey,
         slasher_root: &SlasherRoot,
-        new_resolver_admin: &Pubkey,
+        new_resolver: &Pubkey,
     ) -> TestResult<()> {
         // create resolver + add operator vault
         let slash_proposal = SlashProposal::find_program_address(
@@ -354,7 +686,7 @@ impl ResolverProgramClient {
             &slash_proposal,
             &ncn_slash_proposal_ticket,
             &ncn_root.ncn_admin,
-            new_resolver_admin,
+            new_resolver,
         )
         .await
     }
@@ -367,7 +699,7 @@ impl ResolverProgramClient {
         slash_proposal: &Pubkey,
         ncn_slash_proposal_ticket: &Pubkey,
         ncn_resolver_admin: &Keypair,
-        new_resolver_admin: &Pubkey,
+        new_resolver: &Pubkey,
     ) -> TestResult<()> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
@@ -382,7 +714,7 @@ impl ResolverProgramClient {
                 slash_proposal,
                 ncn_slash_proposal_ticket,
                 &ncn_resolver_admin.pubkey(),
-                new_resolver_admin,
+                new_resolver,
             )],
             Some(&ncn_resolver_admin.pubkey()),
             &[ncn_resolver_admin],
@@ -397,7 +729,11 @@ impl ResolverProgramClient {
         operator: &Pubkey,
         slasher_root: &SlasherRoot,
         resolver_root: &ResolverRoot,
+        reason: &str,
     ) -> TestResult<()> {
+        let mut veto_reason = [0; 128];
+        veto_reason[..reason.len()].copy_from_slice(reason.as_bytes());
+
         let slash_proposal = SlashProposal::find_program_address(
             &resolver_program::id(),
             &ncn,
@@ -411,6 +747,8 @@ impl ResolverProgramClient {
             &slash_proposal,
         )
         .0;
+        let slash_proposal_list =
+            SlashProposalList::find_program_address(&resolver_program::id(), ncn, operator).0;
 
         self.veto_slash(
             ncn,
@@ -419,11 +757,91 @@ impl ResolverProgramClient {
             &resolver_root.resolver_pubkey,
             &slash_proposal,
             &ncn_slash_proposal_ticket,
+            &slash_proposal_list,
             &resolver_root.resolver_admin,
+            veto_reason,
         )
         .await
     }
 
+    /// Vetoes the slash proposals of `operators` in one transaction, returning how many were
+    /// vetoed
+    pub async fn do_veto_slash_batch(
+        &mut self,
+        ncn: &Pubkey,
+        operators: &[Pubkey],
+        slasher_root: &SlasherRoot,
+        resolver_root: &ResolverRoot,
+        reason: &str,
+    ) -> TestResult<u64> {
+        let mut veto_reason = [0; 128];
+        veto_reason[..reason.len()].copy_from_slice(reason.as_bytes());
+
+        let proposals: Vec<BatchVetoProposal> = operators
+            .iter()
+            .map(|operator| {
+                let slash_proposal = SlashProposal::find_program_address(
+                    &resolver_program::id(),
+                    ncn,
+                    operator,
+                    &slasher_root.slasher_pubkey,
+                )
+                .0;
+                BatchVetoProposal {
+                    operator: *operator,
+                    slasher: slasher_root.slasher_pubkey,
+                    slash_proposal,
+                    ncn_slash_proposal_ticket: NcnSlashProposalTicket::find_program_address(
+                        &resolver_program::id(),
+                        ncn,
+                        &slash_proposal,
+                    )
+                    .0,
+                    slash_proposal_list: SlashProposalList::find_program_address(
+                        &resolver_program::id(),
+                        ncn,
+                        operator,
+                    )
+                    .0,
+                }
+            })
+            .collect();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let result = self
+            .banks_client
+            .process_transaction_with_metadata(Transaction::new_signed_with_payer(
+                &[resolver_sdk::sdk::veto_slash_batch(
+                    &resolver_program::id(),
+                    &Config::find_program_address(&resolver_program::id()).0,
+                    &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                    ncn,
+                    &resolver_root.resolver_pubkey,
+                    &resolver_root.resolver_admin.pubkey(),
+                    &NcnSlashStats::find_program_address(&resolver_program::id(), ncn).0,
+                    &proposals,
+                    veto_reason,
+                )],
+                Some(&resolver_root.resolver_admin.pubkey()),
+                &[&resolver_root.resolver_admin],
+                blockhash,
+            ))
+            .await?;
+        result
+            .result
+            .map_err(|e| TestError::BanksClientError(BanksClientError::TransactionError(e)))?;
+
+        let return_data = result
+            .metadata
+            .and_then(|metadata| metadata.return_data)
+            .ok_or(TestError::ReturnDataNotFound)?;
+        let vetoed_count: [u8; 8] = return_data
+            .data
+            .try_into()
+            .map_err(|_| TestError::ReturnDataNotFound)?;
+        Ok(u64::from_le_bytes(vetoed_count))
+    }
+
     async fn veto_slash(
         &mut self,
         ncn: &Pubkey,
@@ -432,7 +850,9 @@ impl ResolverProgramClient {
         resolver: &Pubkey,
         slash_proposal: &Pubkey,
         ncn_slash_proposal_ticket: &Pubkey,
+        slash_proposal_list: &Pubkey,
         resolver_admin: &Keypair,
+        reason: [u8; 128],
     ) -> TestResult<()> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
@@ -447,7 +867,10 @@ impl ResolverProgramClient {
                 resolver,
                 slash_proposal,
                 ncn_slash_proposal_ticket,
+                slash_proposal_list,
+                &NcnSlashStats::find_program_address(&resolver_program::id(), ncn).0,
                 &resolver_admin.pubkey(),
+                reason,
             )],
             Some(&resolver_admin.pubkey()),
             &[resolver_admin],
@@ -532,6 +955,9 @@ impl ResolverProgramClient {
             get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint);
         let slasher_token_account =
             get_associated_token_address(&slasher_root.slasher_pubkey, &vault.supported_mint);
+        let resolver_admin = self.get_account::<Resolver>(resolver).await?.admin;
+        let resolver_token_account =
+            get_associated_token_address(&resolver_admin, &vault.supported_mint);
 
         let slash_proposal = SlashProposal::find_program_address(
             &resolver_program::id(),
@@ -540,12 +966,39 @@ impl ResolverProgramClient {
             &slasher_root.slasher_pubkey,
         )
         .0;
+        // Default destinations route to the treasury
+        let slash_destination = match self
+            .get_account::<SlashProposal>(&slash_proposal)
+            .await?
+            .slash_destination
+        {
+            destination if destination.eq(&Pubkey::default()) => {
+                self.get_account::<NcnResolverProgramConfig>(
+                    &NcnResolverProgramConfig::find_program_address(
+                        &resolver_program::id(),
+                        ncn_pubkey,
+                    )
+                    .0,
+                )
+                .await?
+                .treasury
+            }
+            destination => destination,
+        };
+        let slash_destination_token_account =
+            get_associated_token_address(&slash_destination, &vault.supported_mint);
         let ncn_slash_proposal_ticket = NcnSlashProposalTicket::find_program_address(
             &resolver_program::id(),
             ncn_pubkey,
             &slash_proposal,
         )
         .0;
+        let slash_proposal_list = SlashProposalList::find_program_address(
+            &resolver_program::id(),
+            ncn_pubkey,
+            operator_pubkey,
+        )
+        .0;
 
         self.execute_slash(
             ncn_pubkey,
@@ -562,9 +1015,12 @@ impl ResolverProgramClient {
             &vault_ncn_slasher_operator_ticket,
             &vault_token_account,
             &slasher_token_account,
+            &resolver_token_account,
+            &slash_destination_token_account,
             resolver,
             &slash_proposal,
             &ncn_slash_proposal_ticket,
+            &slash_proposal_list,
         )
         .await
     }
@@ -585,9 +1041,12 @@ impl ResolverProgramClient {
         vault_ncn_slasher_operator_ticket: &Pubkey,
         vault_token_account: &Pubkey,
         slasher_token_account: &Pubkey,
+        resolver_token_account: &Pubkey,
+        slash_destination_token_account: &Pubkey,
         resolver: &Pubkey,
         slash_proposal: &Pubkey,
         ncn_slash_proposal_ticket: &Pubkey,
+        slash_proposal_list: &Pubkey,
     ) -> TestResult<()> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
@@ -612,9 +1071,13 @@ impl ResolverProgramClient {
                 vault_ncn_slasher_operator_ticket,
                 vault_token_account,
                 slasher_token_account,
+                resolver_token_account,
+                slash_destination_token_account,
                 resolver,
                 slash_proposal,
                 ncn_slash_proposal_ticket,
+                slash_proposal_list,
+                &NcnSlashStats::find_program_address(&resolver_program::id(), ncn).0,
             )],
             Some(&slasher_root.slasher_admin.pubkey()),
             &[&slasher_root.slasher_admin],
@@ -631,6 +1094,7 @@ impl ResolverProgramClient {
         token_account: &Pubkey,
         delegate: &Pubkey,
         token_program_id: &Pubkey,
+        max_delegated_amount: u64,
     ) -> TestResult<()> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
@@ -642,6 +1106,7 @@ impl ResolverProgramClient {
                 token_account,
                 delegate,
                 token_program_id,
+                max_delegated_amount,
             )],
             Some(&self.payer.pubkey()),
             &[&self.payer, delegate_admin],
@@ -711,6 +1176,59 @@ impl ResolverProgramClient {
                 slasher,
                 slash_proposal,
                 ncn_slash_proposal_ticket,
+                &SlashProposalList::find_program_address(&resolver_program::id(), ncn, operator).0,
+                &self.payer.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_initialize_ncn_slash_stats(&mut self, ncn: &Pubkey) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::initialize_ncn_slash_stats(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                ncn,
+                &NcnSlashStats::find_program_address(&resolver_program::id(), ncn).0,
+                &self.payer.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_initialize_slash_proposal_list(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+    ) -> TestResult<()> {
+        let slash_proposal_list =
+            SlashProposalList::find_program_address(&resolver_program::id(), ncn, operator).0;
+
+        self.initialize_slash_proposal_list(ncn, operator, &slash_proposal_list)
+            .await
+    }
+
+    pub async fn initialize_slash_proposal_list(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slash_proposal_list: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::initialize_slash_proposal_list(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                ncn,
+                operator,
+                slash_proposal_list,
                 &self.payer.pubkey(),
             )],
             Some(&self.payer.pubkey()),
@@ -720,6 +1238,514 @@ impl ResolverProgramClient {
         .await
     }
 
+    pub async fn do_set_ncn_resolver_program_config_params(
+        &mut self,
+        ncn_root: &NcnRoot,
+        params: NcnResolverProgramConfigParams,
+    ) -> TestResult<()> {
+        self.set_ncn_resolver_program_config_params(
+            &ncn_root.ncn_pubkey,
+            &ncn_root.ncn_admin,
+            params,
+        )
+        .await
+    }
+
+    pub async fn set_ncn_resolver_program_config_params(
+        &mut self,
+        ncn: &Pubkey,
+        ncn_resolver_admin: &Keypair,
+        params: NcnResolverProgramConfigParams,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::set_ncn_resolver_program_config_params(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                ncn,
+                &ncn_resolver_admin.pubkey(),
+                params.resolver_change_delay,
+                params.resolver_share_bps,
+                params.treasury,
+                params.veto_extension_slots,
+                params.max_veto_extensions,
+                params.approval_threshold,
+                params.execution_delay_slots,
+                params.re_veto_window_slots,
+            )],
+            Some(&ncn_resolver_admin.pubkey()),
+            &[ncn_resolver_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_apply_resolver(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher_root: &SlasherRoot,
+    ) -> TestResult<()> {
+        let slash_proposal = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            ncn,
+            operator,
+            &slasher_root.slasher_pubkey,
+        )
+        .0;
+        let ncn_slash_proposal_ticket = NcnSlashProposalTicket::find_program_address(
+            &resolver_program::id(),
+            ncn,
+            &slash_proposal,
+        )
+        .0;
+
+        self.apply_resolver(
+            ncn,
+            operator,
+            &slasher_root.slasher_pubkey,
+            &slash_proposal,
+            &ncn_slash_proposal_ticket,
+        )
+        .await
+    }
+
+    pub async fn apply_resolver(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher: &Pubkey,
+        slash_proposal: &Pubkey,
+        ncn_slash_proposal_ticket: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::apply_resolver(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                ncn,
+                operator,
+                slasher,
+                slash_proposal,
+                ncn_slash_proposal_ticket,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_cancel_resolver_change(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator: &Pubkey,
+        slasher_root: &SlasherRoot,
+    ) -> TestResult<()> {
+        let slash_proposal = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            operator,
+            &slasher_root.slasher_pubkey,
+        )
+        .0;
+        let ncn_slash_proposal_ticket = NcnSlashProposalTicket::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            &slash_proposal,
+        )
+        .0;
+
+        self.cancel_resolver_change(
+            &ncn_root.ncn_pubkey,
+            operator,
+            &slasher_root.slasher_pubkey,
+            &slash_proposal,
+            &ncn_slash_proposal_ticket,
+            &ncn_root.ncn_admin,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn cancel_resolver_change(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher: &Pubkey,
+        slash_proposal: &Pubkey,
+        ncn_slash_proposal_ticket: &Pubkey,
+        ncn_resolver_admin: &Keypair,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::cancel_resolver_change(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                ncn,
+                operator,
+                slasher,
+                slash_proposal,
+                ncn_slash_proposal_ticket,
+                &ncn_resolver_admin.pubkey(),
+            )],
+            Some(&ncn_resolver_admin.pubkey()),
+            &[ncn_resolver_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_reassign_proposal_resolver(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher_root: &SlasherRoot,
+        config_admin: &Keypair,
+        new_resolver: &Pubkey,
+    ) -> TestResult<()> {
+        let slash_proposal = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            ncn,
+            operator,
+            &slasher_root.slasher_pubkey,
+        )
+        .0;
+        let ncn_slash_proposal_ticket = NcnSlashProposalTicket::find_program_address(
+            &resolver_program::id(),
+            ncn,
+            &slash_proposal,
+        )
+        .0;
+
+        self.reassign_proposal_resolver(
+            ncn,
+            operator,
+            &slasher_root.slasher_pubkey,
+            &slash_proposal,
+            &ncn_slash_proposal_ticket,
+            config_admin,
+            new_resolver,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn reassign_proposal_resolver(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher: &Pubkey,
+        slash_proposal: &Pubkey,
+        ncn_slash_proposal_ticket: &Pubkey,
+        config_admin: &Keypair,
+        new_resolver: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::reassign_proposal_resolver(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                ncn,
+                operator,
+                slasher,
+                slash_proposal,
+                ncn_slash_proposal_ticket,
+                &config_admin.pubkey(),
+                new_resolver,
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_migrate_slash_proposal(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher_root: &SlasherRoot,
+        config_admin: &Keypair,
+    ) -> TestResult<()> {
+        let slash_proposal = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            ncn,
+            operator,
+            &slasher_root.slasher_pubkey,
+        )
+        .0;
+
+        self.migrate_slash_proposal(
+            ncn,
+            operator,
+            &slasher_root.slasher_pubkey,
+            &slash_proposal,
+            config_admin,
+        )
+        .await
+    }
+
+    pub async fn migrate_slash_proposal(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher: &Pubkey,
+        slash_proposal: &Pubkey,
+        config_admin: &Keypair,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::migrate_slash_proposal(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                ncn,
+                operator,
+                slasher,
+                slash_proposal,
+                &config_admin.pubkey(),
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_contest_veto(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator: &Pubkey,
+        slasher_root: &SlasherRoot,
+    ) -> TestResult<()> {
+        let slash_proposal = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            operator,
+            &slasher_root.slasher_pubkey,
+        )
+        .0;
+
+        self.contest_veto(
+            &ncn_root.ncn_pubkey,
+            operator,
+            &slasher_root.slasher_pubkey,
+            &slash_proposal,
+            &ncn_root.ncn_admin,
+        )
+        .await
+    }
+
+    pub async fn contest_veto(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher: &Pubkey,
+        slash_proposal: &Pubkey,
+        ncn_resolver_admin: &Keypair,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::contest_veto(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                ncn,
+                operator,
+                slasher,
+                slash_proposal,
+                &ncn_resolver_admin.pubkey(),
+            )],
+            Some(&ncn_resolver_admin.pubkey()),
+            &[ncn_resolver_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_approve_slash(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher_root: &SlasherRoot,
+        approver: &Keypair,
+    ) -> TestResult<()> {
+        let slash_proposal = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            ncn,
+            operator,
+            &slasher_root.slasher_pubkey,
+        )
+        .0;
+
+        self.approve_slash(
+            ncn,
+            operator,
+            &slasher_root.slasher_pubkey,
+            &slash_proposal,
+            approver,
+        )
+        .await
+    }
+
+    pub async fn approve_slash(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher: &Pubkey,
+        slash_proposal: &Pubkey,
+        approver: &Keypair,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::approve_slash(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                ncn,
+                operator,
+                slasher,
+                slash_proposal,
+                &approver.pubkey(),
+            )],
+            Some(&approver.pubkey()),
+            &[approver],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_add_operator_to_blacklist(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator: &Pubkey,
+    ) -> TestResult<()> {
+        self.add_operator_to_blacklist(&ncn_root.ncn_pubkey, operator, &ncn_root.ncn_admin)
+            .await
+    }
+
+    pub async fn add_operator_to_blacklist(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        ncn_resolver_admin: &Keypair,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::add_operator_to_blacklist(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                ncn,
+                operator,
+                &ncn_resolver_admin.pubkey(),
+            )],
+            Some(&ncn_resolver_admin.pubkey()),
+            &[ncn_resolver_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_remove_operator_from_blacklist(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator: &Pubkey,
+    ) -> TestResult<()> {
+        self.remove_operator_from_blacklist(&ncn_root.ncn_pubkey, operator, &ncn_root.ncn_admin)
+            .await
+    }
+
+    pub async fn remove_operator_from_blacklist(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        ncn_resolver_admin: &Keypair,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::remove_operator_from_blacklist(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                ncn,
+                operator,
+                &ncn_resolver_admin.pubkey(),
+            )],
+            Some(&ncn_resolver_admin.pubkey()),
+            &[ncn_resolver_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_add_slash_destination(
+        &mut self,
+        ncn_root: &NcnRoot,
+        slash_destination: Pubkey,
+    ) -> TestResult<()> {
+        self.add_slash_destination(&ncn_root.ncn_pubkey, &ncn_root.ncn_admin, slash_destination)
+            .await
+    }
+
+    pub async fn add_slash_destination(
+        &mut self,
+        ncn: &Pubkey,
+        ncn_resolver_admin: &Keypair,
+        slash_destination: Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::add_slash_destination(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                ncn,
+                &ncn_resolver_admin.pubkey(),
+                slash_destination,
+            )],
+            Some(&ncn_resolver_admin.pubkey()),
+            &[ncn_resolver_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_remove_slash_destination(
+        &mut self,
+        ncn_root: &NcnRoot,
+        slash_destination: Pubkey,
+    ) -> TestResult<()> {
+        self.remove_slash_destination(&ncn_root.ncn_pubkey, &ncn_root.ncn_admin, slash_destination)
+            .await
+    }
+
+    pub async fn remove_slash_destination(
+        &mut self,
+        ncn: &Pubkey,
+        ncn_resolver_admin: &Keypair,
+        slash_destination: Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::remove_slash_destination(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                ncn,
+                &ncn_resolver_admin.pubkey(),
+                slash_destination,
+            )],
+            Some(&ncn_resolver_admin.pubkey()),
+            &[ncn_resolver_admin],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn process_transaction(&mut self, tx: &Transaction) -> TestResult<()> {
         self.banks_client
             .process_transaction_with_preflight_and_commitment(
@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::ncn_resolver_program_config::NcnResolverProgramConfig;
+    use resolver_sdk::error::ResolverError;
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::MAX_SLASH_AMOUNT,
+    };
+
+    #[tokio::test]
+    async fn test_add_operator_to_blacklist_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root: _,
+            ncn_root,
+            operator_roots,
+            slashers_amounts: _,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        resolver_program_client
+            .do_add_operator_to_blacklist(&ncn_root, &operator_pubkey)
+            .await
+            .unwrap();
+
+        let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
+            .get_account(
+                &NcnResolverProgramConfig::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(ncn_resolver_program_config.blacklist_len(), 1);
+        assert!(ncn_resolver_program_config.is_blacklisted(&operator_pubkey));
+    }
+
+    #[tokio::test]
+    async fn test_add_operator_to_blacklist_already_blacklisted_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root: _,
+            ncn_root,
+            operator_roots,
+            slashers_amounts: _,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        resolver_program_client
+            .do_add_operator_to_blacklist(&ncn_root, &operator_pubkey)
+            .await
+            .unwrap();
+
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        let test_error = resolver_program_client
+            .do_add_operator_to_blacklist(&ncn_root, &operator_pubkey)
+            .await;
+        assert_resolver_error(test_error, ResolverError::OperatorAlreadyBlacklisted);
+    }
+}
@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::ncn_resolver_program_config::NcnResolverProgramConfig;
+    use resolver_sdk::error::ResolverError;
+    use solana_program::pubkey::Pubkey;
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::MAX_SLASH_AMOUNT,
+    };
+
+    #[tokio::test]
+    async fn test_add_slash_destination_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slash_destination = Pubkey::new_unique();
+
+        resolver_program_client
+            .do_add_slash_destination(&ncn_root, slash_destination)
+            .await
+            .unwrap();
+
+        let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
+            .get_account(
+                &NcnResolverProgramConfig::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(ncn_resolver_program_config.slash_destinations_len(), 1);
+        assert!(ncn_resolver_program_config.is_slash_destination_allowed(&slash_destination));
+    }
+
+    #[tokio::test]
+    async fn test_add_slash_destination_already_allowed_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slash_destination = Pubkey::new_unique();
+
+        resolver_program_client
+            .do_add_slash_destination(&ncn_root, slash_destination)
+            .await
+            .unwrap();
+
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        let test_error = resolver_program_client
+            .do_add_slash_destination(&ncn_root, slash_destination)
+            .await;
+        assert_resolver_error(test_error, ResolverError::SlashDestinationAlreadyAllowed);
+    }
+
+    #[test]
+    fn test_slash_destination_owner() {
+        let treasury = Pubkey::new_unique();
+        let slash_destination = Pubkey::new_unique();
+        let mut ncn_resolver_program_config = NcnResolverProgramConfig::new(treasury, 0, 0, 0);
+
+        // The default destination is the treasury
+        assert_eq!(
+            ncn_resolver_program_config
+                .slash_destination_owner(&Pubkey::default())
+                .unwrap(),
+            treasury
+        );
+        assert!(matches!(
+            ncn_resolver_program_config.slash_destination_owner(&slash_destination),
+            Err(ResolverError::InvalidSlashDestination)
+        ));
+
+        ncn_resolver_program_config
+            .add_slash_destination(slash_destination)
+            .unwrap();
+        assert_eq!(
+            ncn_resolver_program_config
+                .slash_destination_owner(&slash_destination)
+                .unwrap(),
+            slash_destination
+        );
+    }
+}
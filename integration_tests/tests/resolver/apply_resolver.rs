@@ -0,0 +1,306 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::{
+        ncn_resolver_program_config::NcnResolverProgramConfig,
+        ncn_slash_proposal_ticket::NcnSlashProposalTicket, slash_proposal::SlashProposal,
+    };
+    use resolver_sdk::error::ResolverError;
+    use solana_program::pubkey::Pubkey;
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::{assert_resolver_error, NcnResolverProgramConfigParams},
+        },
+        resolver::MAX_SLASH_AMOUNT,
+    };
+
+    #[tokio::test]
+    async fn test_apply_resolver_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        let new_resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &new_resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(NcnResolverProgramConfig::MIN_RESOLVER_CHANGE_DELAY)
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_apply_resolver(&ncn_root.ncn_pubkey, &operator_pubkey, slasher_root)
+            .await
+            .unwrap();
+
+        let slash_proposal = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_pubkey,
+            &slasher_root.slasher_pubkey,
+        )
+        .0;
+        let ncn_slash_proposal_ticket_pubkey = NcnSlashProposalTicket::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            &slash_proposal,
+        )
+        .0;
+        let ncn_slash_proposal_ticket: NcnSlashProposalTicket = resolver_program_client
+            .get_account(&ncn_slash_proposal_ticket_pubkey)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            ncn_slash_proposal_ticket.resolver,
+            new_resolver_root.resolver_pubkey
+        );
+        assert_eq!(
+            ncn_slash_proposal_ticket.pending_resolver,
+            Pubkey::default()
+        );
+        assert_eq!(
+            ncn_slash_proposal_ticket.pending_resolver_effective_slot(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_apply_resolver_timelock_not_elapsed_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let resolver_change_delay = 10;
+        resolver_program_client
+            .do_set_ncn_resolver_program_config_params(
+                &ncn_root,
+                NcnResolverProgramConfigParams {
+                    resolver_change_delay: Some(resolver_change_delay),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        let new_resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &new_resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        let test_error = resolver_program_client
+            .do_apply_resolver(&ncn_root.ncn_pubkey, &operator_pubkey, slasher_root)
+            .await;
+        assert_resolver_error(test_error, ResolverError::ResolverChangeTimelockNotElapsed);
+
+        fixture
+            .warp_slot_incremental(resolver_change_delay)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_apply_resolver(&ncn_root.ncn_pubkey, &operator_pubkey, slasher_root)
+            .await
+            .unwrap();
+
+        let slash_proposal = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_pubkey,
+            &slasher_root.slasher_pubkey,
+        )
+        .0;
+        let ncn_slash_proposal_ticket_pubkey = NcnSlashProposalTicket::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            &slash_proposal,
+        )
+        .0;
+        let ncn_slash_proposal_ticket: NcnSlashProposalTicket = resolver_program_client
+            .get_account(&ncn_slash_proposal_ticket_pubkey)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            ncn_slash_proposal_ticket.resolver,
+            new_resolver_root.resolver_pubkey
+        );
+    }
+
+    #[tokio::test]
+    async fn test_apply_resolver_not_pending_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let test_error = resolver_program_client
+            .do_apply_resolver(&ncn_root.ncn_pubkey, &operator_pubkey, slasher_root)
+            .await;
+        assert_resolver_error(test_error, ResolverError::ResolverChangeNotPending);
+    }
+}
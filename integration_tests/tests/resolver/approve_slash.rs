@@ -0,0 +1,272 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::slash_proposal::SlashProposal;
+    use resolver_sdk::error::ResolverError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::{assert_resolver_error, NcnResolverProgramConfigParams},
+        },
+        resolver::MAX_SLASH_AMOUNT,
+    };
+
+    #[tokio::test]
+    async fn test_approve_slash_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        resolver_program_client
+            .do_set_ncn_resolver_program_config_params(
+                &ncn_root,
+                NcnResolverProgramConfigParams {
+                    approval_threshold: Some(2),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_approve_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &slasher_root.slasher_admin,
+            )
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_approve_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &ncn_root.ncn_admin,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(
+                &SlashProposal::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    &operator_pubkey,
+                    &slasher_root.slasher_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(slash_proposal.approval_threshold(), 2);
+        assert_eq!(slash_proposal.approvals_count(), 2);
+        assert_eq!(
+            slash_proposal.approvals(),
+            &[
+                slasher_root.slasher_admin.pubkey(),
+                ncn_root.ncn_admin.pubkey()
+            ]
+        );
+        assert!(slash_proposal.check_approval_threshold_reached().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_approve_slash_duplicate_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        resolver_program_client
+            .do_set_ncn_resolver_program_config_params(
+                &ncn_root,
+                NcnResolverProgramConfigParams {
+                    approval_threshold: Some(2),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_approve_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &slasher_root.slasher_admin,
+            )
+            .await
+            .unwrap();
+
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        let result = resolver_program_client
+            .do_approve_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &slasher_root.slasher_admin,
+            )
+            .await;
+
+        assert_resolver_error(result, ResolverError::SlashProposalAlreadyApproved);
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(
+                &SlashProposal::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    &operator_pubkey,
+                    &slasher_root.slasher_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(slash_proposal.approvals_count(), 1);
+        assert!(slash_proposal.check_approval_threshold_reached().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_approve_slash_invalid_approver_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let approver = Keypair::new();
+        resolver_program_client
+            ._airdrop(&approver.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .do_approve_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &approver,
+            )
+            .await;
+
+        assert_resolver_error(result, ResolverError::SlashApproverInvalid);
+    }
+}
@@ -0,0 +1,250 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::{
+        ncn_slash_proposal_ticket::NcnSlashProposalTicket, slash_proposal::SlashProposal,
+    };
+    use resolver_sdk::error::ResolverError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::{assert_resolver_error, NcnResolverProgramConfigParams},
+        },
+        resolver::MAX_SLASH_AMOUNT,
+    };
+
+    #[tokio::test]
+    async fn test_cancel_resolver_change_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_set_ncn_resolver_program_config_params(
+                &ncn_root,
+                NcnResolverProgramConfigParams {
+                    resolver_change_delay: Some(10),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        let new_resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &new_resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_cancel_resolver_change(&ncn_root, &operator_pubkey, slasher_root)
+            .await
+            .unwrap();
+
+        let slash_proposal = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_pubkey,
+            &slasher_root.slasher_pubkey,
+        )
+        .0;
+        let ncn_slash_proposal_ticket_pubkey = NcnSlashProposalTicket::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            &slash_proposal,
+        )
+        .0;
+        let ncn_slash_proposal_ticket: NcnSlashProposalTicket = resolver_program_client
+            .get_account(&ncn_slash_proposal_ticket_pubkey)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            ncn_slash_proposal_ticket.resolver,
+            resolver_root.resolver_pubkey
+        );
+        assert_eq!(
+            ncn_slash_proposal_ticket.pending_resolver,
+            Pubkey::default()
+        );
+
+        fixture.warp_slot_incremental(10).await.unwrap();
+
+        let test_error = resolver_program_client
+            .do_apply_resolver(&ncn_root.ncn_pubkey, &operator_pubkey, slasher_root)
+            .await;
+        assert_resolver_error(test_error, ResolverError::ResolverChangeNotPending);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_resolver_change_wrong_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        let new_resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &new_resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_pubkey,
+            &slasher_root.slasher_pubkey,
+        )
+        .0;
+        let ncn_slash_proposal_ticket_pubkey = NcnSlashProposalTicket::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            &slash_proposal,
+        )
+        .0;
+
+        let wrong_admin = Keypair::new();
+        resolver_program_client
+            ._airdrop(&wrong_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let test_error = resolver_program_client
+            .cancel_resolver_change(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                &slasher_root.slasher_pubkey,
+                &slash_proposal,
+                &ncn_slash_proposal_ticket_pubkey,
+                &wrong_admin,
+            )
+            .await;
+        assert_resolver_error(
+            test_error,
+            ResolverError::NcnResolverProgramConfigAdminInvalid,
+        );
+    }
+}
@@ -0,0 +1,249 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::slash_proposal::SlashProposal;
+    use resolver_sdk::error::ResolverError;
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::{assert_resolver_error, NcnResolverProgramConfigParams},
+        },
+        resolver::{MAX_SLASH_AMOUNT, VETO_DURATION},
+    };
+
+    #[tokio::test]
+    async fn test_contest_veto_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_set_ncn_resolver_program_config_params(
+                &ncn_root,
+                NcnResolverProgramConfigParams {
+                    veto_extension_slots: Some(50),
+                    max_veto_extensions: Some(2),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal_pubkey = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_pubkey,
+            &slasher_root.slasher_pubkey,
+        )
+        .0;
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_pubkey)
+            .await
+            .unwrap();
+        let veto_deadline_slot = slash_proposal.veto_deadline_slot();
+
+        resolver_program_client
+            .do_contest_veto(&ncn_root, &operator_pubkey, slasher_root)
+            .await
+            .unwrap();
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_pubkey)
+            .await
+            .unwrap();
+
+        assert_eq!(slash_proposal.veto_deadline_slot(), veto_deadline_slot + 50);
+        assert_eq!(slash_proposal.extensions_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_contest_veto_max_extensions_reached_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_set_ncn_resolver_program_config_params(
+                &ncn_root,
+                NcnResolverProgramConfigParams {
+                    veto_extension_slots: Some(50),
+                    max_veto_extensions: Some(1),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_contest_veto(&ncn_root, &operator_pubkey, slasher_root)
+            .await
+            .unwrap();
+
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        let test_error = resolver_program_client
+            .do_contest_veto(&ncn_root, &operator_pubkey, slasher_root)
+            .await;
+        assert_resolver_error(
+            test_error,
+            ResolverError::SlashProposalMaxVetoExtensionsReached,
+        );
+
+        let slash_proposal_pubkey = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_pubkey,
+            &slasher_root.slasher_pubkey,
+        )
+        .0;
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_pubkey)
+            .await
+            .unwrap();
+
+        assert_eq!(slash_proposal.extensions_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_contest_veto_period_ended_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_set_ncn_resolver_program_config_params(
+                &ncn_root,
+                NcnResolverProgramConfigParams {
+                    veto_extension_slots: Some(50),
+                    max_veto_extensions: Some(1),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        fixture.warp_slot_incremental(VETO_DURATION).await.unwrap();
+
+        let test_error = resolver_program_client
+            .do_contest_veto(&ncn_root, &operator_pubkey, slasher_root)
+            .await;
+        assert_resolver_error(test_error, ResolverError::SlashProposalVetoPeriodEnded);
+    }
+}
@@ -3,10 +3,14 @@ mod tests {
     use resolver_core::{
         ncn_slash_proposal_ticket::NcnSlashProposalTicket, slash_proposal::SlashProposal,
     };
+    use resolver_sdk::error::ResolverError;
 
     use crate::{
-        fixtures::fixture::{ConfiguredVault, TestBuilder},
-        resolver::MAX_SLASH_AMOUNT,
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::{DELETE_SLASH_PROPOSAL_DURATION, MAX_SLASH_AMOUNT},
     };
 
     #[tokio::test]
@@ -24,7 +28,7 @@ mod tests {
             vault_program_client: _,
             restaking_program_client: _,
             vault_config_admin: _,
-            vault_root: _,
+            vault_root,
             ncn_root,
             operator_roots,
             slashers_amounts,
@@ -52,6 +56,7 @@ mod tests {
                 &ncn_root.ncn_pubkey,
                 &operator_roots[0].operator_pubkey,
                 &slasher_root,
+                &vault_root.vault_pubkey,
                 100,
             )
             .await
@@ -88,6 +93,7 @@ mod tests {
                 &operator_roots[0].operator_pubkey,
                 &slasher_root,
                 &resolver_root,
+                "",
             )
             .await
             .unwrap();
@@ -114,4 +120,128 @@ mod tests {
             .await
             .is_err());
     }
+
+    #[tokio::test]
+    async fn test_delete_slash_proposal_before_delete_deadline_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal_pubkey = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_pubkey,
+            &slasher_root.slasher_pubkey,
+        )
+        .0;
+        let ncn_slash_proposal_ticket_pubkey = NcnSlashProposalTicket::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            &slash_proposal_pubkey,
+        )
+        .0;
+        let proposed: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_pubkey)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &resolver_root,
+                "",
+            )
+            .await
+            .unwrap();
+
+        // Completing the proposal sets the delete deadline and leaves the veto deadline alone
+        let vetoed: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_pubkey)
+            .await
+            .unwrap();
+        let delete_deadline_slot =
+            u64::from(proposed.capture_slot) + DELETE_SLASH_PROPOSAL_DURATION;
+        assert_eq!(vetoed.veto_deadline_slot(), proposed.veto_deadline_slot());
+        assert_eq!(vetoed.delete_deadline_slot(), delete_deadline_slot);
+
+        let result = resolver_program_client
+            .delete_slash_proposal(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                &slasher_root.slasher_pubkey,
+                &slash_proposal_pubkey,
+                &ncn_slash_proposal_ticket_pubkey,
+            )
+            .await;
+        assert_resolver_error(result, ResolverError::SlashProposalDeletePeriodNotEnded);
+
+        let current_slot = fixture.get_current_slot().await.unwrap();
+        fixture
+            .warp_slot_incremental(delete_deadline_slot - current_slot)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .delete_slash_proposal(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                &slasher_root.slasher_pubkey,
+                &slash_proposal_pubkey,
+                &ncn_slash_proposal_ticket_pubkey,
+            )
+            .await
+            .unwrap();
+    }
 }
@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use resolver_sdk::error::ResolverError;
+    use solana_program::program_error::ProgramError;
+
+    const ALL_ERRORS: [ResolverError; 45] = [
+        ResolverError::ResolverAdminInvalid,
+        ResolverError::SlasherAdminInvalid,
+        ResolverError::SlasherDelegateAdminInvalid,
+        ResolverError::SlashProposalVetoPeriodEnded,
+        ResolverError::SlashProposalVetoPeriodNotEnded,
+        ResolverError::SlashProposalCompleted,
+        ResolverError::SlashProposalInvalid,
+        ResolverError::SlashProposalResolverInvalid,
+        ResolverError::SlashProposalDeletePeriodNotEnded,
+        ResolverError::NcnResolverProgramConfigAdminInvalid,
+        ResolverError::ResolverChangeNotPending,
+        ResolverError::ResolverChangeTimelockNotElapsed,
+        ResolverError::ResolverShareBpsInvalid,
+        ResolverError::SlashProposalMaxVetoExtensionsReached,
+        ResolverError::OperatorBlacklisted,
+        ResolverError::OperatorAlreadyBlacklisted,
+        ResolverError::OperatorNotBlacklisted,
+        ResolverError::BlacklistFull,
+        ResolverError::SlashBpsInvalid,
+        ResolverError::ConfigAlreadyInitialized,
+        ResolverError::NcnResolverProgramConfigAlreadyInitialized,
+        ResolverError::ResolverAlreadyInitialized,
+        ResolverError::SlashBatchInvalid,
+        ResolverError::ApprovalThresholdInvalid,
+        ResolverError::ApprovalThresholdNotReached,
+        ResolverError::SlashApproverInvalid,
+        ResolverError::SlashProposalAlreadyApproved,
+        ResolverError::ExecutionDelayNotElapsed,
+        ResolverError::ResolverNcnMismatch,
+        ResolverError::SlasherProposeAdminInvalid,
+        ResolverError::SlasherExecuteAdminInvalid,
+        ResolverError::ResolverIsOperator,
+        ResolverError::SlashBalanceMismatch,
+        ResolverError::ConfigAdminInvalid,
+        ResolverError::InvalidSlashDestination,
+        ResolverError::SlashDestinationAlreadyAllowed,
+        ResolverError::SlashDestinationAllowlistFull,
+        ResolverError::OutstandingSlashExceedsStake,
+        ResolverError::ResolverChangeDelayTooShort,
+        ResolverError::ReVetoWindowTooShort,
+        ResolverError::ExecutionDelayTooShort,
+        ResolverError::VetoExtensionTooShort,
+        ResolverError::ArithmeticOverflow,
+        ResolverError::ArithmeticUnderflow,
+        ResolverError::DivisionByZero,
+    ];
+
+    #[test]
+    fn test_resolver_error_code_round_trip() {
+        let mut codes = HashSet::new();
+        for error in ALL_ERRORS {
+            let code = error as u32;
+            assert!(codes.insert(code), "{error} shares code {code}");
+            assert_eq!(ResolverError::try_from(code).unwrap(), error);
+            assert_eq!(ProgramError::from(error), ProgramError::Custom(code));
+        }
+
+        // Every code the conversion knows about is covered above
+        let mapped = (0..=u16::MAX as u32)
+            .filter(|code| ResolverError::try_from(*code).is_ok())
+            .count();
+        assert_eq!(mapped, ALL_ERRORS.len());
+    }
+
+    #[test]
+    fn test_resolver_error_unknown_code_fails() {
+        assert_eq!(
+            ResolverError::try_from(u32::MAX),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn test_resolver_error_display() {
+        assert_eq!(
+            ResolverError::SlashBpsInvalid.to_string(),
+            "Slash bps exceeds 10,000"
+        );
+        assert_eq!(
+            ResolverError::try_from(ResolverError::ArithmeticOverflow as u32)
+                .unwrap()
+                .to_string(),
+            "Arithmetic overflow"
+        );
+    }
+}
@@ -4,17 +4,20 @@ mod tests {
         vault::Vault, vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
         vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
     };
-    use resolver_core::slash_proposal::SlashProposal;
-    use resolver_sdk::error::ResolverError;
-    use solana_sdk::{signature::Keypair, signer::Signer};
+    use resolver_core::{
+        ncn_resolver_program_config::NcnResolverProgramConfig, ncn_slash_stats::NcnSlashStats,
+        slash_proposal::SlashProposal,
+    };
+    use resolver_sdk::{error::ResolverError, event::SlashExecuted};
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
     use spl_associated_token_account::get_associated_token_address;
 
     use crate::{
         fixtures::{
             fixture::{ConfiguredVault, TestBuilder},
-            resolver_client::assert_resolver_error,
+            resolver_client::{assert_resolver_error, NcnResolverProgramConfigParams},
         },
-        resolver::{DELEGATION_AMOUNT, MAX_SLASH_AMOUNT, MINT_AMOUNT},
+        resolver::{DELEGATION_AMOUNT, MAX_SLASH_AMOUNT, MINT_AMOUNT, VETO_DURATION},
     };
 
     #[tokio::test]
@@ -97,6 +100,10 @@ mod tests {
             .create_ata(&vault.supported_mint, &slasher_root.slasher_pubkey)
             .await
             .unwrap();
+        fixture
+            .create_ata(&vault.supported_mint, &ncn_root.ncn_admin.pubkey())
+            .await
+            .unwrap();
 
         let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
         vault_program_client
@@ -137,12 +144,22 @@ mod tests {
                 &ncn_root.ncn_pubkey,
                 &operator_roots[0].operator_pubkey,
                 &slasher_root,
+                &vault_root.vault_pubkey,
                 100,
             )
             .await
             .unwrap();
 
-        fixture.warp_slot_incremental(101).await.unwrap();
+        fixture
+            .warp_slot_incremental(
+                VETO_DURATION + NcnResolverProgramConfig::MIN_EXECUTION_DELAY_SLOTS,
+            )
+            .await
+            .unwrap();
+
+        let vault_ata =
+            get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint);
+        let balance_before = fixture.get_token_account(&vault_ata).await.unwrap().amount;
 
         resolver_program_client
             .do_execute_slash(
@@ -173,18 +190,38 @@ mod tests {
         assert_eq!(slash_proposal.amount(), 100);
         assert!(slash_proposal.completed());
 
+        let ncn_slash_stats: NcnSlashStats = resolver_program_client
+            .get_account(
+                &NcnSlashStats::find_program_address(&resolver_program::id(), &ncn_root.ncn_pubkey)
+                    .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(ncn_slash_stats.total_executed(), 1);
+        assert_eq!(ncn_slash_stats.total_amount_executed(), 100);
+
+        // The vault balance backing the operator drops by exactly the slashed amount
+        let balance_after = fixture.get_token_account(&vault_ata).await.unwrap().amount;
+        assert_eq!(balance_before - balance_after, slash_proposal.amount());
+
         let vault: Vault = resolver_program_client
             .get_account(&vault_root.vault_pubkey)
             .await
             .unwrap();
-        let ata = get_associated_token_address(&slasher_root.slasher_pubkey, &vault.supported_mint);
-        let token_account_acc = fixture.get_token_account(&ata).await.unwrap();
+        let slasher_ata =
+            get_associated_token_address(&slasher_root.slasher_pubkey, &vault.supported_mint);
+        let slasher_token_account = fixture.get_token_account(&slasher_ata).await.unwrap();
+        let treasury_ata =
+            get_associated_token_address(&ncn_root.ncn_admin.pubkey(), &vault.supported_mint);
+        let treasury_token_account = fixture.get_token_account(&treasury_ata).await.unwrap();
 
-        assert_eq!(token_account_acc.amount, 100);
+        // Without a resolver assigned, the treasury receives the full slashed amount
+        assert_eq!(slasher_token_account.amount, 0);
+        assert_eq!(treasury_token_account.amount, 100);
     }
 
     #[tokio::test]
-    async fn test_execute_slash_veto_period_not_ended_fails() {
+    async fn test_execute_slash_approval_threshold_ok() {
         let mut fixture = TestBuilder::new().await;
         let mut resolver_program_client = fixture.resolver_program_client();
 
@@ -263,6 +300,10 @@ mod tests {
             .create_ata(&vault.supported_mint, &slasher_root.slasher_pubkey)
             .await
             .unwrap();
+        fixture
+            .create_ata(&vault.supported_mint, &ncn_root.ncn_admin.pubkey())
+            .await
+            .unwrap();
 
         let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
         vault_program_client
@@ -298,17 +339,46 @@ mod tests {
             .await
             .unwrap();
 
+        resolver_program_client
+            .do_set_ncn_resolver_program_config_params(
+                &ncn_root,
+                NcnResolverProgramConfigParams {
+                    approval_threshold: Some(2),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
         resolver_program_client
             .do_propose_slash(
                 &ncn_root.ncn_pubkey,
                 &operator_roots[0].operator_pubkey,
                 &slasher_root,
+                &vault_root.vault_pubkey,
                 100,
             )
             .await
             .unwrap();
 
-        let test_result = resolver_program_client
+        resolver_program_client
+            .do_approve_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slasher_root.slasher_admin,
+            )
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(
+                VETO_DURATION + NcnResolverProgramConfig::MIN_EXECUTION_DELAY_SLOTS,
+            )
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
             .do_execute_slash(
                 &ncn_root.ncn_pubkey,
                 &operator_roots[0].operator_pubkey,
@@ -317,12 +387,48 @@ mod tests {
                 &resolver_root.resolver_pubkey,
             )
             .await;
+        assert_resolver_error(result, ResolverError::ApprovalThresholdNotReached);
 
-        assert_resolver_error(test_result, ResolverError::SlashProposalVetoPeriodNotEnded);
+        resolver_program_client
+            .do_approve_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &ncn_root.ncn_admin,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_execute_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(
+                &SlashProposal::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    &operator_roots[0].operator_pubkey,
+                    &slasher_root.slasher_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(slash_proposal.approvals_count(), 2);
+        assert!(slash_proposal.completed());
     }
 
     #[tokio::test]
-    async fn test_execute_slash_completed_fails() {
+    async fn test_execute_slash_split_between_resolver_and_treasury_ok() {
         let mut fixture = TestBuilder::new().await;
         let mut resolver_program_client = fixture.resolver_program_client();
 
@@ -401,6 +507,10 @@ mod tests {
             .create_ata(&vault.supported_mint, &slasher_root.slasher_pubkey)
             .await
             .unwrap();
+        fixture
+            .create_ata(&vault.supported_mint, &ncn_root.ncn_admin.pubkey())
+            .await
+            .unwrap();
 
         let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
         vault_program_client
@@ -435,13 +545,32 @@ mod tests {
             .do_initialize_resolver(&ncn_root)
             .await
             .unwrap();
+        fixture
+            .create_ata(
+                &vault.supported_mint,
+                &resolver_root.resolver_admin.pubkey(),
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_set_ncn_resolver_program_config_params(
+                &ncn_root,
+                NcnResolverProgramConfigParams {
+                    resolver_share_bps: Some(3_000),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
 
         resolver_program_client
             .do_propose_slash(
                 &ncn_root.ncn_pubkey,
                 &operator_roots[0].operator_pubkey,
                 &slasher_root,
-                100,
+                &vault_root.vault_pubkey,
+                99,
             )
             .await
             .unwrap();
@@ -456,19 +585,191 @@ mod tests {
             .await
             .unwrap();
 
+        fixture
+            .warp_slot_incremental(
+                VETO_DURATION + NcnResolverProgramConfig::MIN_EXECUTION_DELAY_SLOTS,
+            )
+            .await
+            .unwrap();
+
         resolver_program_client
-            .do_veto_slash(
+            .do_execute_slash(
                 &ncn_root.ncn_pubkey,
                 &operator_roots[0].operator_pubkey,
-                slasher_root,
-                &resolver_root,
+                &slasher_root,
+                &vault_root,
+                &resolver_root.resolver_pubkey,
             )
             .await
             .unwrap();
 
-        fixture.warp_slot_incremental(100).await.unwrap();
+        let vault: Vault = resolver_program_client
+            .get_account(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let resolver_ata = get_associated_token_address(
+            &resolver_root.resolver_admin.pubkey(),
+            &vault.supported_mint,
+        );
+        let resolver_token_account = fixture.get_token_account(&resolver_ata).await.unwrap();
+        let treasury_ata =
+            get_associated_token_address(&ncn_root.ncn_admin.pubkey(), &vault.supported_mint);
+        let treasury_token_account = fixture.get_token_account(&treasury_ata).await.unwrap();
+
+        // 30% of 99 is 29.7, the resolver's share rounds down and the treasury's rounds up
+        assert_eq!(resolver_token_account.amount, 29);
+        assert_eq!(treasury_token_account.amount, 70);
+    }
 
-        let test_result = resolver_program_client
+    #[tokio::test]
+    async fn test_execute_slash_to_allowed_slash_destination_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            restaking_program_client: _,
+            vault_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let operator_root = &operator_roots[0];
+        vault_program_client
+            .do_add_delegation(
+                &vault_root,
+                &operator_root.operator_pubkey,
+                DELEGATION_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+            )
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        let operator_root_pubkeys: Vec<_> =
+            operator_roots.iter().map(|r| r.operator_pubkey).collect();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_root_pubkeys)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        // configure slasher and slash
+        let slasher_root = &slashers_amounts[0].0;
+
+        fixture
+            .create_ata(&vault.supported_mint, &slasher_root.slasher_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .create_ata(&vault.supported_mint, &ncn_root.ncn_admin.pubkey())
+            .await
+            .unwrap();
+
+        let slash_destination = Pubkey::new_unique();
+        fixture
+            .create_ata(&vault.supported_mint, &slash_destination)
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_add_slash_destination(&ncn_root, slash_destination)
+            .await
+            .unwrap();
+
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        vault_program_client
+            .initialize_vault_ncn_slasher_operator_ticket(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                &slasher_root.slasher_pubkey,
+                &operator_root.operator_pubkey,
+                &VaultNcnSlasherTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                )
+                .0,
+                &VaultNcnSlasherOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                    &operator_root.operator_pubkey,
+                    epoch,
+                )
+                .0,
+                &vault_config_admin,
+            )
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_propose_slash_with_destination(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+                slash_destination,
+            )
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(
+                VETO_DURATION + NcnResolverProgramConfig::MIN_EXECUTION_DELAY_SLOTS,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
             .do_execute_slash(
                 &ncn_root.ncn_pubkey,
                 &operator_roots[0].operator_pubkey,
@@ -476,8 +777,1156 @@ mod tests {
                 &vault_root,
                 &resolver_root.resolver_pubkey,
             )
-            .await;
+            .await
+            .unwrap();
 
-        assert_resolver_error(test_result, ResolverError::SlashProposalCompleted);
+        let slash_destination_ata =
+            get_associated_token_address(&slash_destination, &vault.supported_mint);
+        let slash_destination_token_account = fixture
+            .get_token_account(&slash_destination_ata)
+            .await
+            .unwrap();
+        let treasury_ata =
+            get_associated_token_address(&ncn_root.ncn_admin.pubkey(), &vault.supported_mint);
+        let treasury_token_account = fixture.get_token_account(&treasury_ata).await.unwrap();
+
+        // The slashed amount goes to the proposal's destination instead of the treasury
+        assert_eq!(slash_destination_token_account.amount, 100);
+        assert_eq!(treasury_token_account.amount, 0);
+    }
+
+    #[tokio::test]
+    async fn test_execute_slash_to_disallowed_slash_destination_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            restaking_program_client: _,
+            vault_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let operator_root = &operator_roots[0];
+        vault_program_client
+            .do_add_delegation(
+                &vault_root,
+                &operator_root.operator_pubkey,
+                DELEGATION_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+            )
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        let operator_root_pubkeys: Vec<_> =
+            operator_roots.iter().map(|r| r.operator_pubkey).collect();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_root_pubkeys)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        // configure slasher and slash
+        let slasher_root = &slashers_amounts[0].0;
+
+        fixture
+            .create_ata(&vault.supported_mint, &slasher_root.slasher_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .create_ata(&vault.supported_mint, &ncn_root.ncn_admin.pubkey())
+            .await
+            .unwrap();
+
+        let slash_destination = Pubkey::new_unique();
+
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        vault_program_client
+            .initialize_vault_ncn_slasher_operator_ticket(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                &slasher_root.slasher_pubkey,
+                &operator_root.operator_pubkey,
+                &VaultNcnSlasherTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                )
+                .0,
+                &VaultNcnSlasherOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                    &operator_root.operator_pubkey,
+                    epoch,
+                )
+                .0,
+                &vault_config_admin,
+            )
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_propose_slash_with_destination(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+                slash_destination,
+            )
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(
+                VETO_DURATION + NcnResolverProgramConfig::MIN_EXECUTION_DELAY_SLOTS,
+            )
+            .await
+            .unwrap();
+
+        let test_error = resolver_program_client
+            .do_execute_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await;
+        assert_resolver_error(test_error, ResolverError::InvalidSlashDestination);
+    }
+
+    #[tokio::test]
+    async fn test_execute_slash_insufficient_delegation_reopens_veto_window() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            restaking_program_client: _,
+            vault_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let operator_root = &operator_roots[0];
+        let slasher_root = &slashers_amounts[0].0;
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        // After the proposal, the operator's stake drops to 50 once the cooldown completes
+        vault_program_client
+            .do_cooldown_delegation(
+                &vault_root,
+                &operator_root.operator_pubkey,
+                DELEGATION_AMOUNT,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator_root.operator_pubkey, 50)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+            )
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        let operator_root_pubkeys: Vec<_> =
+            operator_roots.iter().map(|r| r.operator_pubkey).collect();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_root_pubkeys)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        // configure slasher and slash
+        fixture
+            .create_ata(&vault.supported_mint, &slasher_root.slasher_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .create_ata(&vault.supported_mint, &ncn_root.ncn_admin.pubkey())
+            .await
+            .unwrap();
+
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        vault_program_client
+            .initialize_vault_ncn_slasher_operator_ticket(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                &slasher_root.slasher_pubkey,
+                &operator_root.operator_pubkey,
+                &VaultNcnSlasherTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                )
+                .0,
+                &VaultNcnSlasherOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                    &operator_root.operator_pubkey,
+                    epoch,
+                )
+                .0,
+                &vault_config_admin,
+            )
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(
+                VETO_DURATION + NcnResolverProgramConfig::MIN_EXECUTION_DELAY_SLOTS,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_set_ncn_resolver_program_config_params(
+                &ncn_root,
+                NcnResolverProgramConfigParams {
+                    re_veto_window_slots: Some(50),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        // The operator only has 50 delegated, so the slash of 100 can't go through
+        resolver_program_client
+            .do_execute_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        let attempt_slot = fixture.get_current_slot().await.unwrap();
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(
+                &SlashProposal::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    &operator_roots[0].operator_pubkey,
+                    &slasher_root.slasher_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+
+        assert!(!slash_proposal.completed());
+        assert_eq!(slash_proposal.execution_attempts(), 1);
+        assert_eq!(slash_proposal.last_attempt_slot(), attempt_slot);
+        assert_eq!(slash_proposal.veto_deadline_slot(), attempt_slot + 50);
+        assert!(slash_proposal.check_veto_period_ended(attempt_slot).is_ok());
+
+        // The next attempt has to wait out the reopened veto window
+        fixture.warp_slot_incremental(1).await.unwrap();
+        let test_error = resolver_program_client
+            .do_execute_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await;
+        assert_resolver_error(test_error, ResolverError::SlashProposalVetoPeriodNotEnded);
+    }
+
+    #[tokio::test]
+    async fn test_veto_slash_after_failed_execution_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            restaking_program_client: _,
+            vault_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let operator_root = &operator_roots[0];
+        let slasher_root = &slashers_amounts[0].0;
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        // After the proposal, the operator's stake drops to 50 once the cooldown completes
+        vault_program_client
+            .do_cooldown_delegation(
+                &vault_root,
+                &operator_root.operator_pubkey,
+                DELEGATION_AMOUNT,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator_root.operator_pubkey, 50)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+            )
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        let operator_root_pubkeys: Vec<_> =
+            operator_roots.iter().map(|r| r.operator_pubkey).collect();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_root_pubkeys)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        // configure slasher and slash
+        fixture
+            .create_ata(&vault.supported_mint, &slasher_root.slasher_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .create_ata(&vault.supported_mint, &ncn_root.ncn_admin.pubkey())
+            .await
+            .unwrap();
+
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        vault_program_client
+            .initialize_vault_ncn_slasher_operator_ticket(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                &slasher_root.slasher_pubkey,
+                &operator_root.operator_pubkey,
+                &VaultNcnSlasherTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                )
+                .0,
+                &VaultNcnSlasherOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                    &operator_root.operator_pubkey,
+                    epoch,
+                )
+                .0,
+                &vault_config_admin,
+            )
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(
+                VETO_DURATION + NcnResolverProgramConfig::MIN_EXECUTION_DELAY_SLOTS,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        // The attempt can't cover the slash and reopens the veto window for the default length
+        resolver_program_client
+            .do_execute_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        let attempt_slot = fixture.get_current_slot().await.unwrap();
+        let slash_proposal_pubkey = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_roots[0].operator_pubkey,
+            &slasher_root.slasher_pubkey,
+        )
+        .0;
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            slash_proposal.veto_deadline_slot(),
+            attempt_slot + NcnResolverProgramConfig::MIN_RE_VETO_WINDOW_SLOTS
+        );
+
+        fixture.warp_slot_incremental(1).await.unwrap();
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &resolver_root,
+                "stake moved before execution",
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert!(slash_proposal.completed());
+        assert_eq!(slash_proposal.veto_resolver, resolver_root.resolver_pubkey);
+    }
+
+    #[tokio::test]
+    async fn test_execute_slash_veto_period_not_ended_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            restaking_program_client: _,
+            vault_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let operator_root = &operator_roots[0];
+        vault_program_client
+            .do_add_delegation(
+                &vault_root,
+                &operator_root.operator_pubkey,
+                DELEGATION_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+            )
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        let operator_root_pubkeys: Vec<_> =
+            operator_roots.iter().map(|r| r.operator_pubkey).collect();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_root_pubkeys)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        // configure slasher and slash
+        let slasher_root = &slashers_amounts[0].0;
+
+        fixture
+            .create_ata(&vault.supported_mint, &slasher_root.slasher_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .create_ata(&vault.supported_mint, &ncn_root.ncn_admin.pubkey())
+            .await
+            .unwrap();
+
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        vault_program_client
+            .initialize_vault_ncn_slasher_operator_ticket(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                &slasher_root.slasher_pubkey,
+                &operator_root.operator_pubkey,
+                &VaultNcnSlasherTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                )
+                .0,
+                &VaultNcnSlasherOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                    &operator_root.operator_pubkey,
+                    epoch,
+                )
+                .0,
+                &vault_config_admin,
+            )
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let test_result = resolver_program_client
+            .do_execute_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await;
+
+        assert_resolver_error(test_result, ResolverError::SlashProposalVetoPeriodNotEnded);
+    }
+
+    #[tokio::test]
+    async fn test_execute_slash_veto_deadline_and_execution_delay_boundaries() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            restaking_program_client: _,
+            vault_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let operator_root = &operator_roots[0];
+        vault_program_client
+            .do_add_delegation(
+                &vault_root,
+                &operator_root.operator_pubkey,
+                DELEGATION_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+            )
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        let operator_root_pubkeys: Vec<_> =
+            operator_roots.iter().map(|r| r.operator_pubkey).collect();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_root_pubkeys)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        // configure slasher and slash
+        let slasher_root = &slashers_amounts[0].0;
+
+        fixture
+            .create_ata(&vault.supported_mint, &slasher_root.slasher_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .create_ata(&vault.supported_mint, &ncn_root.ncn_admin.pubkey())
+            .await
+            .unwrap();
+
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        vault_program_client
+            .initialize_vault_ncn_slasher_operator_ticket(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                &slasher_root.slasher_pubkey,
+                &operator_root.operator_pubkey,
+                &VaultNcnSlasherTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                )
+                .0,
+                &VaultNcnSlasherOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                    &operator_root.operator_pubkey,
+                    epoch,
+                )
+                .0,
+                &vault_config_admin,
+            )
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal_pubkey = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_roots[0].operator_pubkey,
+            &slasher_root.slasher_pubkey,
+        )
+        .0;
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_pubkey)
+            .await
+            .unwrap();
+        let veto_deadline_slot = slash_proposal.veto_deadline_slot();
+
+        // The last vetoable slot is not executable
+        fixture.warp_to_slot(veto_deadline_slot - 1).unwrap();
+        let test_result = resolver_program_client
+            .do_execute_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await;
+        assert_resolver_error(test_result, ResolverError::SlashProposalVetoPeriodNotEnded);
+
+        // Once the veto window closes, the execution delay still has to pass
+        fixture.warp_to_slot(veto_deadline_slot).unwrap();
+        let test_result = resolver_program_client
+            .do_execute_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await;
+        assert_resolver_error(test_result, ResolverError::ExecutionDelayNotElapsed);
+
+        let executable_after_slot = slash_proposal.executable_after_slot().unwrap();
+        assert_eq!(
+            executable_after_slot,
+            veto_deadline_slot + NcnResolverProgramConfig::MIN_EXECUTION_DELAY_SLOTS
+        );
+        fixture.warp_to_slot(executable_after_slot - 1).unwrap();
+        let test_result = resolver_program_client
+            .do_execute_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await;
+        assert_resolver_error(test_result, ResolverError::ExecutionDelayNotElapsed);
+
+        // executable_after_slot is inclusive for execution
+        fixture.warp_to_slot(executable_after_slot).unwrap();
+        resolver_program_client
+            .do_execute_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert!(slash_proposal.completed());
+    }
+
+    #[tokio::test]
+    async fn test_execute_slash_completed_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            restaking_program_client: _,
+            vault_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let operator_root = &operator_roots[0];
+        vault_program_client
+            .do_add_delegation(
+                &vault_root,
+                &operator_root.operator_pubkey,
+                DELEGATION_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+            )
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        let operator_root_pubkeys: Vec<_> =
+            operator_roots.iter().map(|r| r.operator_pubkey).collect();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_root_pubkeys)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        // configure slasher and slash
+        let slasher_root = &slashers_amounts[0].0;
+
+        fixture
+            .create_ata(&vault.supported_mint, &slasher_root.slasher_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .create_ata(&vault.supported_mint, &ncn_root.ncn_admin.pubkey())
+            .await
+            .unwrap();
+
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        vault_program_client
+            .initialize_vault_ncn_slasher_operator_ticket(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                &slasher_root.slasher_pubkey,
+                &operator_root.operator_pubkey,
+                &VaultNcnSlasherTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                )
+                .0,
+                &VaultNcnSlasherOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                    &operator_root.operator_pubkey,
+                    epoch,
+                )
+                .0,
+                &vault_config_admin,
+            )
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+        fixture
+            .create_ata(
+                &vault.supported_mint,
+                &resolver_root.resolver_admin.pubkey(),
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &resolver_root,
+                "",
+            )
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(
+                VETO_DURATION + NcnResolverProgramConfig::MIN_EXECUTION_DELAY_SLOTS,
+            )
+            .await
+            .unwrap();
+
+        let test_result = resolver_program_client
+            .do_execute_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await;
+
+        assert_resolver_error(test_result, ResolverError::SlashProposalCompleted);
+    }
+
+    #[test]
+    fn test_check_execution_delay_elapsed_boundary() {
+        let veto_deadline_slot = 200;
+        let mut slash_proposal = SlashProposal::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+            100,
+            veto_deadline_slot,
+            0,
+            255,
+        );
+        slash_proposal.set_execution_delay_slots(50);
+
+        assert_eq!(slash_proposal.executable_after_slot().unwrap(), 250);
+        assert!(matches!(
+            slash_proposal.check_execution_delay_elapsed(249),
+            Err(ResolverError::ExecutionDelayNotElapsed)
+        ));
+        assert!(slash_proposal.check_execution_delay_elapsed(250).is_ok());
+        assert!(slash_proposal.check_execution_delay_elapsed(251).is_ok());
+    }
+
+    #[test]
+    fn test_veto_deadline_boundary() {
+        let veto_deadline_slot = 200;
+        let slash_proposal = SlashProposal::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+            100,
+            veto_deadline_slot,
+            0,
+            255,
+        );
+
+        // Vetoable up to, but excluding, the deadline
+        assert!(slash_proposal
+            .check_veto_period_ended(veto_deadline_slot - 1)
+            .is_ok());
+        assert!(matches!(
+            slash_proposal.check_veto_period_not_ended(veto_deadline_slot - 1),
+            Err(ResolverError::SlashProposalVetoPeriodNotEnded)
+        ));
+
+        // Executable from the deadline onwards
+        assert!(matches!(
+            slash_proposal.check_veto_period_ended(veto_deadline_slot),
+            Err(ResolverError::SlashProposalVetoPeriodEnded)
+        ));
+        assert!(slash_proposal
+            .check_veto_period_not_ended(veto_deadline_slot)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_slash_executed_balance_invariant() {
+        let proposal = Pubkey::new_unique();
+        let operator = Pubkey::new_unique();
+
+        let event = SlashExecuted::new(proposal, operator, 100, 1_000, 900, 42).unwrap();
+        assert_eq!(
+            event.vault_balance_before - event.vault_balance_after,
+            event.amount_slashed
+        );
+
+        assert!(matches!(
+            SlashExecuted::new(proposal, operator, 100, 1_000, 901, 42),
+            Err(ResolverError::SlashBalanceMismatch)
+        ));
+        assert!(matches!(
+            SlashExecuted::new(proposal, operator, 100, 900, 1_000, 42),
+            Err(ResolverError::SlashBalanceMismatch)
+        ));
     }
 }
@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::ncn_resolver_program_config::NcnResolverProgramConfig;
+    use solana_sdk::{epoch_schedule::DEFAULT_SLOTS_PER_EPOCH, pubkey::Pubkey, signer::Signer};
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::NcnResolverProgramConfigParams,
+        },
+        resolver::{DELETE_SLASH_PROPOSAL_DURATION, MAX_SLASH_AMOUNT, VETO_DURATION},
+    };
+
+    #[tokio::test]
+    async fn test_get_config_status_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root: _,
+            ncn_root,
+            operator_roots: _,
+            slashers_amounts: _,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_set_ncn_resolver_program_config_params(
+                &ncn_root,
+                NcnResolverProgramConfigParams {
+                    resolver_share_bps: Some(2500),
+                    approval_threshold: Some(2),
+                    execution_delay_slots: Some(20),
+                    re_veto_window_slots: Some(30),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_add_slash_destination(&ncn_root, Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let status = resolver_program_client
+            .get_config_status(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        assert_eq!(status.epoch_length, DEFAULT_SLOTS_PER_EPOCH);
+        assert_eq!(status.resolver_admin, ncn_root.ncn_admin.pubkey());
+        assert_eq!(status.veto_duration, VETO_DURATION);
+        assert_eq!(
+            status.delete_slash_proposal_duration,
+            DELETE_SLASH_PROPOSAL_DURATION
+        );
+        assert_eq!(status.resolver_count, 1);
+        assert_eq!(
+            status.resolver_change_delay,
+            NcnResolverProgramConfig::MIN_RESOLVER_CHANGE_DELAY
+        );
+        assert_eq!(status.treasury, ncn_root.ncn_admin.pubkey());
+        assert_eq!(status.resolver_share_bps, 2500);
+        assert_eq!(status.blacklist_len, 0);
+        assert_eq!(status.approval_threshold, 2);
+        assert_eq!(status.execution_delay_slots, 20);
+        assert_eq!(status.re_veto_window_slots, 30);
+        assert_eq!(status.slash_destinations_len, 1);
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::{
+        ncn_resolver_program_config::NcnResolverProgramConfig,
+        slash_proposal::{SlashProposal, SlashProposalState},
+    };
+    use solana_sdk::pubkey::Pubkey;
+
+    use crate::{
+        fixtures::fixture::{ConfiguredVault, TestBuilder},
+        resolver::{MAX_SLASH_AMOUNT, VETO_DURATION},
+    };
+
+    #[tokio::test]
+    async fn test_get_slash_proposal_status_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let status = resolver_program_client
+            .get_slash_proposal_status(&ncn_root.ncn_pubkey, &operator_pubkey, slasher_root)
+            .await
+            .unwrap();
+
+        assert_eq!(status.operator, operator_pubkey);
+        assert_eq!(status.slasher, slasher_root.slasher_pubkey);
+        assert_eq!(status.amount, 100);
+        assert_eq!(
+            status.veto_deadline_slot,
+            status.capture_slot + VETO_DURATION
+        );
+        assert_eq!(
+            status.executable_after_slot,
+            status.veto_deadline_slot + NcnResolverProgramConfig::MIN_EXECUTION_DELAY_SLOTS
+        );
+        assert_eq!(
+            SlashProposalState::try_from(status.state).unwrap(),
+            SlashProposalState::InVetoWindow
+        );
+
+        fixture
+            .warp_slot_incremental(
+                VETO_DURATION + NcnResolverProgramConfig::MIN_EXECUTION_DELAY_SLOTS + 1,
+            )
+            .await
+            .unwrap();
+
+        let status = resolver_program_client
+            .get_slash_proposal_status(&ncn_root.ncn_pubkey, &operator_pubkey, slasher_root)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            SlashProposalState::try_from(status.state).unwrap(),
+            SlashProposalState::Executable
+        );
+    }
+
+    #[test]
+    fn test_slash_proposal_state_transitions() {
+        let capture_slot = 100;
+        let veto_deadline_slot = 200;
+        let new_slash_proposal = || {
+            SlashProposal::new(
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                100,
+                capture_slot,
+                veto_deadline_slot,
+                0,
+                255,
+            )
+        };
+
+        // Veto window, then executable once it closes
+        let slash_proposal = new_slash_proposal();
+        assert_eq!(
+            slash_proposal.state(veto_deadline_slot - 1),
+            SlashProposalState::InVetoWindow
+        );
+        assert_eq!(
+            slash_proposal.state(veto_deadline_slot),
+            SlashProposalState::Executable
+        );
+
+        // Waiting on the execution delay
+        let mut slash_proposal = new_slash_proposal();
+        slash_proposal.set_execution_delay_slots(10);
+        assert_eq!(
+            slash_proposal.state(veto_deadline_slot),
+            SlashProposalState::AwaitingExecution
+        );
+        assert_eq!(
+            slash_proposal.state(veto_deadline_slot + 9),
+            SlashProposalState::AwaitingExecution
+        );
+        assert_eq!(
+            slash_proposal.state(veto_deadline_slot + 10),
+            SlashProposalState::Executable
+        );
+
+        // Waiting on approvals
+        let mut slash_proposal = new_slash_proposal();
+        slash_proposal.set_approval_threshold(1);
+        assert_eq!(
+            slash_proposal.state(veto_deadline_slot),
+            SlashProposalState::AwaitingExecution
+        );
+        slash_proposal.approve(Pubkey::new_unique()).unwrap();
+        assert_eq!(
+            slash_proposal.state(veto_deadline_slot),
+            SlashProposalState::Executable
+        );
+
+        // Vetoed, then expired once the delete deadline is reached
+        let mut slash_proposal = new_slash_proposal();
+        slash_proposal.set_completed(true);
+        slash_proposal.set_veto(Pubkey::new_unique(), [0; 128]);
+        slash_proposal.set_delete_deadline_slot(300);
+        assert_eq!(slash_proposal.state(150), SlashProposalState::Vetoed);
+        assert_eq!(slash_proposal.state(299), SlashProposalState::Vetoed);
+        assert_eq!(slash_proposal.state(300), SlashProposalState::Expired);
+
+        // Executed, then expired once the delete deadline is reached
+        let mut slash_proposal = new_slash_proposal();
+        slash_proposal.set_completed(true);
+        slash_proposal.set_delete_deadline_slot(300);
+        assert_eq!(slash_proposal.state(299), SlashProposalState::Completed);
+        assert_eq!(slash_proposal.state(300), SlashProposalState::Expired);
+    }
+}
@@ -1,10 +1,11 @@
 #[cfg(test)]
 mod tests {
     use resolver_core::config::Config;
+    use resolver_sdk::error::ResolverError;
     use solana_program::epoch_schedule::DEFAULT_SLOTS_PER_EPOCH;
     use solana_sdk::{signature::Keypair, signer::Signer};
 
-    use crate::fixtures::fixture::TestBuilder;
+    use crate::fixtures::{fixture::TestBuilder, resolver_client::assert_resolver_error};
 
     #[tokio::test]
     async fn test_initialize_config_ok() {
@@ -31,4 +32,31 @@ mod tests {
         assert_eq!(config.jito_vault_program, jito_vault_program::id());
         assert_eq!(config.epoch_length(), DEFAULT_SLOTS_PER_EPOCH);
     }
+
+    #[tokio::test]
+    async fn test_initialize_config_twice_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let config = Config::find_program_address(&resolver_program::id()).0;
+
+        let config_admin = Keypair::new();
+        fixture
+            .transfer(&config_admin.pubkey(), 10.0)
+            .await
+            .unwrap();
+        resolver_program_client
+            .initialize_config(&config, &config_admin)
+            .await
+            .unwrap();
+
+        let other_admin = Keypair::new();
+        fixture.transfer(&other_admin.pubkey(), 10.0).await.unwrap();
+        let result = resolver_program_client
+            .initialize_config(&config, &other_admin)
+            .await;
+
+        assert_resolver_error(result, ResolverError::ConfigAlreadyInitialized);
+    }
 }
@@ -1,10 +1,14 @@
 #[cfg(test)]
 mod tests {
-    use resolver_core::ncn_resolver_program_config::NcnResolverProgramConfig;
-    use solana_sdk::signer::Signer;
+    use resolver_core::{config::Config, ncn_resolver_program_config::NcnResolverProgramConfig};
+    use resolver_sdk::error::ResolverError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
 
     use crate::{
-        fixtures::fixture::{ConfiguredVault, TestBuilder},
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
         resolver::{DELETE_SLASH_PROPOSAL_DURATION, MAX_SLASH_AMOUNT, VETO_DURATION},
     };
 
@@ -59,5 +63,65 @@ mod tests {
             DELETE_SLASH_PROPOSAL_DURATION
         );
         assert_eq!(ncn_resolver_program_config.resolver_count(), 0);
+        assert_eq!(
+            ncn_resolver_program_config.resolver_change_delay(),
+            NcnResolverProgramConfig::MIN_RESOLVER_CHANGE_DELAY
+        );
+        assert_eq!(
+            ncn_resolver_program_config.execution_delay_slots(),
+            NcnResolverProgramConfig::MIN_EXECUTION_DELAY_SLOTS
+        );
+    }
+
+    #[tokio::test]
+    async fn test_initialize_ncn_resolver_program_config_twice_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            vault_config_admin: _,
+            vault_root: _,
+            ncn_root,
+            operator_roots: _,
+            slashers_amounts: _,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let admin = Keypair::new();
+        resolver_program_client
+            ._airdrop(&admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .do_initialize_ncn_resolver_program_config(
+                &Config::find_program_address(&resolver_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                &admin,
+                VETO_DURATION,
+                DELETE_SLASH_PROPOSAL_DURATION,
+            )
+            .await;
+
+        assert_resolver_error(
+            result,
+            ResolverError::NcnResolverProgramConfigAlreadyInitialized,
+        );
     }
 }
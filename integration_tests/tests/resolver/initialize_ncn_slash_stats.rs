@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::ncn_slash_stats::NcnSlashStats;
+
+    use crate::{
+        fixtures::fixture::{ConfiguredVault, TestBuilder},
+        resolver::MAX_SLASH_AMOUNT,
+    };
+
+    #[tokio::test]
+    async fn test_initialize_ncn_slash_stats_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let ncn_slash_stats: NcnSlashStats = resolver_program_client
+            .get_account(
+                &NcnSlashStats::find_program_address(&resolver_program::id(), &ncn_root.ncn_pubkey)
+                    .0,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(ncn_slash_stats.ncn, ncn_root.ncn_pubkey);
+        assert_eq!(ncn_slash_stats.total_proposals(), 0);
+        assert_eq!(ncn_slash_stats.total_amount_proposed(), 0);
+        assert_eq!(ncn_slash_stats.total_executed(), 0);
+        assert_eq!(ncn_slash_stats.total_amount_executed(), 0);
+        assert_eq!(ncn_slash_stats.total_vetoed(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_slash_stats_track_proposals_and_vetoes() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 2, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        for (operator_root, amount) in operator_roots.iter().zip([100, 50]) {
+            resolver_program_client
+                .do_propose_slash(
+                    &ncn_root.ncn_pubkey,
+                    &operator_root.operator_pubkey,
+                    slasher_root,
+                    &vault_root.vault_pubkey,
+                    amount,
+                )
+                .await
+                .unwrap();
+        }
+
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_roots[1].operator_pubkey,
+                slasher_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[1].operator_pubkey,
+                slasher_root,
+                &resolver_root,
+                "operator was not at fault",
+            )
+            .await
+            .unwrap();
+
+        let ncn_slash_stats: NcnSlashStats = resolver_program_client
+            .get_account(
+                &NcnSlashStats::find_program_address(&resolver_program::id(), &ncn_root.ncn_pubkey)
+                    .0,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(ncn_slash_stats.total_proposals(), 2);
+        assert_eq!(ncn_slash_stats.total_amount_proposed(), 150);
+        assert_eq!(ncn_slash_stats.total_executed(), 0);
+        assert_eq!(ncn_slash_stats.total_amount_executed(), 0);
+        assert_eq!(ncn_slash_stats.total_vetoed(), 1);
+    }
+
+    #[test]
+    fn test_ncn_slash_stats_record_saturates() {
+        let mut ncn_slash_stats = NcnSlashStats::new(Default::default(), 0);
+
+        ncn_slash_stats.record_proposal(u64::MAX);
+        ncn_slash_stats.record_proposal(1);
+        ncn_slash_stats.record_execution(u64::MAX);
+        ncn_slash_stats.record_execution(1);
+
+        assert_eq!(ncn_slash_stats.total_proposals(), 2);
+        assert_eq!(ncn_slash_stats.total_amount_proposed(), u64::MAX);
+        assert_eq!(ncn_slash_stats.total_executed(), 2);
+        assert_eq!(ncn_slash_stats.total_amount_executed(), u64::MAX);
+    }
+}
@@ -3,11 +3,15 @@ mod tests {
     use resolver_core::{
         config::Config, ncn_resolver_program_config::NcnResolverProgramConfig, resolver::Resolver,
     };
+    use resolver_sdk::error::ResolverError;
     use solana_sdk::{signature::Keypair, signer::Signer};
 
     use crate::{
-        fixtures::fixture::TestBuilder,
-        resolver::{DELETE_SLASH_PROPOSAL_DURATION, VETO_DURATION},
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::{DELETE_SLASH_PROPOSAL_DURATION, MAX_SLASH_AMOUNT, VETO_DURATION},
     };
 
     #[tokio::test]
@@ -104,6 +108,7 @@ mod tests {
             .unwrap();
 
         assert_eq!(resolver.admin, resolver_root.resolver_admin.pubkey());
+        assert_eq!(resolver.ncn, ncn_root.ncn_pubkey);
         assert_eq!(resolver.index(), 0);
 
         let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
@@ -119,4 +124,60 @@ mod tests {
 
         assert_eq!(ncn_resolver_program_config.resolver_count(), 1);
     }
+
+    #[tokio::test]
+    async fn test_initialize_resolver_twice_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            vault_config_admin: _,
+            vault_root: _,
+            ncn_root,
+            operator_roots: _,
+            slashers_amounts: _,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let base = Keypair::new();
+        let resolver_pubkey =
+            Resolver::find_program_address(&resolver_program::id(), &base.pubkey()).0;
+
+        let admin = Keypair::new();
+        resolver_program_client
+            ._airdrop(&admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+        resolver_program_client
+            .initialize_resolver(&ncn_root.ncn_pubkey, &resolver_pubkey, &admin, &base)
+            .await
+            .unwrap();
+
+        let other_admin = Keypair::new();
+        resolver_program_client
+            ._airdrop(&other_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+        let result = resolver_program_client
+            .initialize_resolver(&ncn_root.ncn_pubkey, &resolver_pubkey, &other_admin, &base)
+            .await;
+
+        assert_resolver_error(result, ResolverError::ResolverAlreadyInitialized);
+    }
 }
@@ -0,0 +1,183 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::{
+        slash_proposal::SlashProposal,
+        slash_proposal_list::{SlashProposalList, SlashProposalStatus},
+    };
+
+    use crate::{
+        fixtures::fixture::{ConfiguredVault, TestBuilder},
+        resolver::MAX_SLASH_AMOUNT,
+    };
+
+    #[tokio::test]
+    async fn test_initialize_slash_proposal_list_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root: _,
+            ncn_root,
+            operator_roots,
+            slashers_amounts: _,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal_list: SlashProposalList = resolver_program_client
+            .get_account(
+                &SlashProposalList::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    &operator_roots[0].operator_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(slash_proposal_list.ncn, ncn_root.ncn_pubkey);
+        assert_eq!(
+            slash_proposal_list.operator,
+            operator_roots[0].operator_pubkey
+        );
+        assert_eq!(slash_proposal_list.count(), 0);
+        assert!(slash_proposal_list.entries().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_slash_proposal_list_tracks_proposals_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT, MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+        for (slasher_root, _) in slashers_amounts.iter() {
+            resolver_program_client
+                .do_propose_slash(
+                    &ncn_root.ncn_pubkey,
+                    &operator_pubkey,
+                    slasher_root,
+                    &vault_root.vault_pubkey,
+                    100,
+                )
+                .await
+                .unwrap();
+        }
+
+        let vetoed_slasher_root = &slashers_amounts[0].0;
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_pubkey,
+                vetoed_slasher_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                vetoed_slasher_root,
+                &resolver_root,
+                "",
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal_list: SlashProposalList = resolver_program_client
+            .get_account(
+                &SlashProposalList::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    &operator_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(slash_proposal_list.count(), 2);
+        assert_eq!(slash_proposal_list.entries().len(), 2);
+
+        let vetoed_slash_proposal = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_pubkey,
+            &vetoed_slasher_root.slasher_pubkey,
+        )
+        .0;
+        let open_slash_proposal = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_pubkey,
+            &slashers_amounts[1].0.slasher_pubkey,
+        )
+        .0;
+
+        assert_eq!(
+            slash_proposal_list
+                .get(&vetoed_slash_proposal)
+                .unwrap()
+                .status()
+                .unwrap(),
+            SlashProposalStatus::Vetoed
+        );
+        assert_eq!(
+            slash_proposal_list
+                .get(&open_slash_proposal)
+                .unwrap()
+                .status()
+                .unwrap(),
+            SlashProposalStatus::Proposed
+        );
+    }
+}
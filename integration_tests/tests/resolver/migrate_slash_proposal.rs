@@ -0,0 +1,183 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::slash_proposal::SlashProposal;
+    use resolver_sdk::error::ResolverError;
+    use solana_program::pubkey::Pubkey;
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::{assert_resolver_error, NcnResolverProgramConfigParams},
+        },
+        resolver::MAX_SLASH_AMOUNT,
+    };
+
+    #[tokio::test]
+    async fn test_migrate_slash_proposal_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            resolver_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+        let slasher_root = &slashers_amounts[0].0;
+
+        resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        let proposed_slash = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        // The migration gates the proposal with the NCN's current settings
+        resolver_program_client
+            .do_set_ncn_resolver_program_config_params(
+                &ncn_root,
+                NcnResolverProgramConfigParams {
+                    approval_threshold: Some(2),
+                    execution_delay_slots: Some(20),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        // Stand in for a proposal created before any fields were added to the layout
+        let slash_proposal_pubkey = proposed_slash.proposal;
+        fixture
+            .truncate_account(&slash_proposal_pubkey, SlashProposal::LEGACY_SIZE)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_migrate_slash_proposal(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &resolver_config_admin,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            fixture
+                .get_account_data_len(&slash_proposal_pubkey)
+                .await
+                .unwrap(),
+            8 + std::mem::size_of::<SlashProposal>()
+        );
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_pubkey)
+            .await
+            .unwrap();
+
+        // The legacy fields are kept, the gates come from the config and the rest start zeroed
+        assert_eq!(slash_proposal.version(), SlashProposal::CURRENT_VERSION);
+        assert_eq!(slash_proposal.operator, operator_pubkey);
+        assert_eq!(slash_proposal.slasher, slasher_root.slasher_pubkey);
+        assert_eq!(slash_proposal.amount(), 100);
+        assert_eq!(
+            slash_proposal.veto_deadline_slot(),
+            proposed_slash.veto_deadline_slot
+        );
+        assert!(!slash_proposal.completed());
+        assert_eq!(slash_proposal.veto_resolver, Pubkey::default());
+        assert_eq!(slash_proposal.slash_bps(), 0);
+        assert_eq!(slash_proposal.batch_leader, Pubkey::default());
+        assert_eq!(slash_proposal.approval_threshold(), 2);
+        assert_eq!(slash_proposal.execution_delay_slots(), 20);
+        assert_eq!(slash_proposal.approvals_count(), 0);
+        assert_eq!(slash_proposal.slash_destination, Pubkey::default());
+        assert_eq!(slash_proposal.execution_attempts(), 0);
+        assert_eq!(slash_proposal.last_attempt_slot(), 0);
+
+        // Migrating again leaves the account untouched
+        fixture.warp_slot_incremental(1).await.unwrap();
+        resolver_program_client
+            .do_migrate_slash_proposal(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &resolver_config_admin,
+            )
+            .await
+            .unwrap();
+
+        let remigrated_slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(remigrated_slash_proposal, slash_proposal);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_slash_proposal_not_config_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+        let slasher_root = &slashers_amounts[0].0;
+
+        resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        let proposed_slash = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        fixture
+            .truncate_account(&proposed_slash.proposal, SlashProposal::LEGACY_SIZE)
+            .await
+            .unwrap();
+
+        let test_error = resolver_program_client
+            .do_migrate_slash_proposal(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &slasher_root.slasher_admin,
+            )
+            .await;
+        assert_resolver_error(test_error, ResolverError::ConfigAdminInvalid);
+    }
+}
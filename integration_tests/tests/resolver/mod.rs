@@ -1,15 +1,34 @@
+mod add_operator_to_blacklist;
+mod add_slash_destination;
+mod apply_resolver;
+mod approve_slash;
+mod cancel_resolver_change;
+mod contest_veto;
 mod delete_slash_proposal;
+mod error;
 mod execute_slash;
+mod get_config_status;
+mod get_slash_proposal_status;
 mod initialize_config;
 mod initialize_ncn_resolver_program_config;
+mod initialize_ncn_slash_stats;
 mod initialize_resolver;
+mod initialize_slash_proposal_list;
 mod initialize_slasher;
+mod migrate_slash_proposal;
 mod propose_slash;
+mod propose_slash_batch;
+mod propose_slash_bps;
+mod reassign_proposal_resolver;
+mod remove_operator_from_blacklist;
+mod remove_slash_destination;
+mod set_ncn_resolver_program_config_params;
 mod set_resolver;
 mod slasher_delegate_token_account;
 mod slasher_set_admin;
 mod slasher_set_secondary_admin;
 mod veto_slash;
+mod veto_slash_batch;
 
 pub(crate) const MINT_AMOUNT: u64 = 100_000;
 pub(crate) const DELEGATION_AMOUNT: u64 = 10_000;
@@ -1,10 +1,16 @@
 #[cfg(test)]
 mod tests {
-    use resolver_core::slash_proposal::SlashProposal;
+    use resolver_core::{slash_proposal::SlashProposal, slash_proposal_list::SlashProposalList};
+    use resolver_sdk::error::ResolverError;
+    use solana_program::instruction::InstructionError;
 
     use crate::{
-        fixtures::fixture::{ConfiguredVault, TestBuilder},
-        resolver::MAX_SLASH_AMOUNT,
+        fixtures::{
+            assert_ix_error,
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::{DELEGATION_AMOUNT, MAX_SLASH_AMOUNT, MINT_AMOUNT, VETO_DURATION},
     };
 
     #[tokio::test]
@@ -22,7 +28,7 @@ mod tests {
             vault_program_client: _,
             restaking_program_client: _,
             vault_config_admin: _,
-            vault_root: _,
+            vault_root,
             ncn_root,
             operator_roots,
             slashers_amounts,
@@ -45,32 +51,201 @@ mod tests {
             .await
             .unwrap();
 
-        resolver_program_client
+        let proposed_slash = resolver_program_client
             .do_propose_slash(
                 &ncn_root.ncn_pubkey,
                 &operator_roots[0].operator_pubkey,
                 &slasher_root,
+                &vault_root.vault_pubkey,
                 100,
             )
             .await
             .unwrap();
 
+        let slash_proposal_pubkey = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_roots[0].operator_pubkey,
+            &slasher_root.slasher_pubkey,
+        )
+        .0;
         let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_pubkey)
+            .await
+            .unwrap();
+
+        assert_eq!(slash_proposal.operator, operator_roots[0].operator_pubkey);
+        assert_eq!(slash_proposal.slasher, slasher_root.slasher_pubkey);
+        assert_eq!(slash_proposal.amount(), 100);
+        assert!(!slash_proposal.completed());
+
+        assert_eq!(proposed_slash.proposal, slash_proposal_pubkey);
+        assert_eq!(
+            proposed_slash.veto_deadline_slot,
+            slash_proposal.veto_deadline_slot()
+        );
+        assert_eq!(
+            proposed_slash.veto_deadline_slot,
+            proposed_slash.capture_slot + VETO_DURATION
+        );
+    }
+
+    #[tokio::test]
+    async fn test_propose_slash_operator_blacklisted_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        resolver_program_client
+            .do_add_operator_to_blacklist(&ncn_root, &operator_pubkey)
+            .await
+            .unwrap();
+
+        let test_error = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await;
+        assert_resolver_error(test_error, ResolverError::OperatorBlacklisted);
+    }
+
+    #[tokio::test]
+    async fn test_propose_slash_outstanding_exceeds_stake_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT, MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+        let slash_amount = DELEGATION_AMOUNT / 2 + 1;
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                &slashers_amounts[0].0,
+                &vault_root.vault_pubkey,
+                slash_amount,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal_list: SlashProposalList = resolver_program_client
             .get_account(
-                &SlashProposal::find_program_address(
+                &SlashProposalList::find_program_address(
                     &resolver_program::id(),
                     &ncn_root.ncn_pubkey,
-                    &operator_roots[0].operator_pubkey,
-                    &slasher_root.slasher_pubkey,
+                    &operator_pubkey,
                 )
                 .0,
             )
             .await
             .unwrap();
+        assert_eq!(slash_proposal_list.outstanding_slash_amount(), slash_amount);
 
-        assert_eq!(slash_proposal.operator, operator_roots[0].operator_pubkey);
-        assert_eq!(slash_proposal.slasher, slasher_root.slasher_pubkey);
-        assert_eq!(slash_proposal.amount(), 100);
-        assert!(!slash_proposal.completed());
+        // Each proposal fits the stake on its own, but together they exceed it
+        let test_error = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                &slashers_amounts[1].0,
+                &vault_root.vault_pubkey,
+                slash_amount,
+            )
+            .await;
+        assert_resolver_error(test_error, ResolverError::OutstandingSlashExceedsStake);
+    }
+
+    #[tokio::test]
+    async fn test_propose_slash_unregistered_vault_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        // The unregistered vault's stake would otherwise lift the cap above DELEGATION_AMOUNT
+        let unregistered_vault_root = fixture
+            .setup_unregistered_vault_for_operator(&operator_roots[0], MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let test_error = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &unregistered_vault_root.vault_pubkey,
+                DELEGATION_AMOUNT + 1,
+            )
+            .await;
+        assert_ix_error(test_error, InstructionError::InvalidAccountOwner);
     }
 }
@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::slash_proposal::SlashProposal;
+    use resolver_sdk::error::ResolverError;
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::MAX_SLASH_AMOUNT,
+    };
+
+    #[tokio::test]
+    async fn test_propose_slash_batch_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 3;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        // 1/2/1 of 103 is 25.75/51.5/25.75, floored to 25/51/25 with the remaining 2 going to the
+        // first operator
+        let weights = [1, 2, 1];
+        let operators: Vec<_> = operator_roots
+            .iter()
+            .zip(weights)
+            .map(|(operator_root, weight)| (operator_root.operator_pubkey, weight))
+            .collect();
+        resolver_program_client
+            .do_propose_slash_batch(
+                &ncn_root.ncn_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                &operators,
+                103,
+            )
+            .await
+            .unwrap();
+
+        let mut slash_proposals = Vec::with_capacity(operators.len());
+        for (operator, _) in operators.iter() {
+            let slash_proposal_pubkey = SlashProposal::find_program_address(
+                &resolver_program::id(),
+                &ncn_root.ncn_pubkey,
+                operator,
+                &slasher_root.slasher_pubkey,
+            )
+            .0;
+            let slash_proposal: SlashProposal = resolver_program_client
+                .get_account(&slash_proposal_pubkey)
+                .await
+                .unwrap();
+            assert_eq!(slash_proposal.operator, *operator);
+            slash_proposals.push((slash_proposal_pubkey, slash_proposal));
+        }
+
+        let amounts: Vec<u64> = slash_proposals
+            .iter()
+            .map(|(_, slash_proposal)| slash_proposal.amount())
+            .collect();
+        assert_eq!(amounts, vec![27, 51, 25]);
+        assert_eq!(amounts.iter().sum::<u64>(), 103);
+
+        let (batch_leader, first) = slash_proposals[0];
+        for (_, slash_proposal) in slash_proposals.iter() {
+            assert_eq!(slash_proposal.batch_leader, batch_leader);
+            assert_eq!(slash_proposal.capture_slot, first.capture_slot);
+            assert_eq!(
+                slash_proposal.veto_deadline_slot(),
+                first.veto_deadline_slot()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_propose_slash_batch_zero_weight_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 2;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let operators: Vec<_> = operator_roots
+            .iter()
+            .map(|operator_root| (operator_root.operator_pubkey, 0))
+            .collect();
+        let result = resolver_program_client
+            .do_propose_slash_batch(
+                &ncn_root.ncn_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                &operators,
+                100,
+            )
+            .await;
+
+        assert_resolver_error(result, ResolverError::SlashBatchInvalid);
+    }
+}
@@ -0,0 +1,189 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::slash_proposal::SlashProposal;
+    use resolver_sdk::error::ResolverError;
+    use solana_program::instruction::InstructionError;
+
+    use crate::{
+        fixtures::{
+            assert_ix_error,
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::{MAX_SLASH_AMOUNT, MINT_AMOUNT},
+    };
+
+    #[tokio::test]
+    async fn test_propose_slash_bps_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        // On top of the fixture's delegation, 2500 bps of 10_003 is 2500.75, rounded down to 2500
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator_pubkey, 3)
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_propose_slash_bps(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                2500,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(
+                &SlashProposal::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    &operator_pubkey,
+                    &slasher_root.slasher_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(slash_proposal.operator, operator_pubkey);
+        assert_eq!(slash_proposal.slash_bps(), 2500);
+        assert_eq!(slash_proposal.amount(), 2500);
+        assert!(!slash_proposal.completed());
+    }
+
+    #[tokio::test]
+    async fn test_propose_slash_bps_invalid_bps_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .do_propose_slash_bps(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                10_001,
+            )
+            .await;
+
+        assert_resolver_error(result, ResolverError::SlashBpsInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_propose_slash_bps_unregistered_vault_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        // The operator has stake in this vault, but the vault has no slasher ticket for the NCN
+        let unregistered_vault_root = fixture
+            .setup_unregistered_vault_for_operator(&operator_roots[0], MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+        let slasher_root = &slashers_amounts[0].0;
+
+        let result = resolver_program_client
+            .do_propose_slash_bps(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &unregistered_vault_root.vault_pubkey,
+                10_000,
+            )
+            .await;
+
+        assert_ix_error(result, InstructionError::InvalidAccountOwner);
+    }
+}
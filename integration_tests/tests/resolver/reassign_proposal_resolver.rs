@@ -0,0 +1,182 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::{
+        ncn_slash_proposal_ticket::NcnSlashProposalTicket, slash_proposal::SlashProposal,
+    };
+    use resolver_sdk::error::ResolverError;
+    use solana_program::pubkey::Pubkey;
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::MAX_SLASH_AMOUNT,
+    };
+
+    #[tokio::test]
+    async fn test_reassign_proposal_resolver_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            resolver_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+        let slasher_root = &slashers_amounts[0].0;
+
+        let unavailable_resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+        let new_resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        let proposed_slash = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &unavailable_resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_reassign_proposal_resolver(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &resolver_config_admin,
+                &new_resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        let ncn_slash_proposal_ticket: NcnSlashProposalTicket = resolver_program_client
+            .get_account(
+                &NcnSlashProposalTicket::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    &proposed_slash.proposal,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            ncn_slash_proposal_ticket.resolver,
+            new_resolver_root.resolver_pubkey
+        );
+        assert_eq!(
+            ncn_slash_proposal_ticket.pending_resolver,
+            Pubkey::default()
+        );
+
+        // The proposal keeps its address and deadlines
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&proposed_slash.proposal)
+            .await
+            .unwrap();
+        assert_eq!(
+            u64::from(slash_proposal.capture_slot),
+            proposed_slash.capture_slot
+        );
+        assert_eq!(
+            slash_proposal.veto_deadline_slot(),
+            proposed_slash.veto_deadline_slot
+        );
+
+        // Only the new resolver can veto
+        let result = resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &unavailable_resolver_root,
+                "operator was not at fault",
+            )
+            .await;
+        assert_resolver_error(result, ResolverError::SlashProposalResolverInvalid);
+
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &new_resolver_root,
+                "operator was not at fault",
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_reassign_proposal_resolver_not_config_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+        let slasher_root = &slashers_amounts[0].0;
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .do_reassign_proposal_resolver(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &ncn_root.ncn_admin,
+                &resolver_root.resolver_pubkey,
+            )
+            .await;
+        assert_resolver_error(result, ResolverError::ConfigAdminInvalid);
+    }
+}
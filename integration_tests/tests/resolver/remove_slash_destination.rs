@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::ncn_resolver_program_config::NcnResolverProgramConfig;
+    use resolver_sdk::error::ResolverError;
+    use solana_program::pubkey::Pubkey;
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::MAX_SLASH_AMOUNT,
+    };
+
+    #[tokio::test]
+    async fn test_remove_slash_destination_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slash_destination = Pubkey::new_unique();
+
+        resolver_program_client
+            .do_add_slash_destination(&ncn_root, slash_destination)
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_remove_slash_destination(&ncn_root, slash_destination)
+            .await
+            .unwrap();
+
+        let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
+            .get_account(
+                &NcnResolverProgramConfig::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(ncn_resolver_program_config.slash_destinations_len(), 0);
+        assert!(!ncn_resolver_program_config.is_slash_destination_allowed(&slash_destination));
+    }
+
+    #[tokio::test]
+    async fn test_remove_slash_destination_not_allowed_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let test_error = resolver_program_client
+            .do_remove_slash_destination(&ncn_root, Pubkey::new_unique())
+            .await;
+        assert_resolver_error(test_error, ResolverError::InvalidSlashDestination);
+    }
+}
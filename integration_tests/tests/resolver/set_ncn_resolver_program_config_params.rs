@@ -0,0 +1,270 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::ncn_resolver_program_config::NcnResolverProgramConfig;
+    use resolver_sdk::error::ResolverError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::{assert_resolver_error, NcnResolverProgramConfigParams},
+        },
+        resolver::{MAX_SLASH_AMOUNT, VETO_DURATION},
+    };
+
+    #[tokio::test]
+    async fn test_set_ncn_resolver_program_config_params_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            vault_config_admin: _,
+            vault_root: _,
+            ncn_root,
+            operator_roots: _,
+            slashers_amounts: _,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let treasury = Pubkey::new_unique();
+        resolver_program_client
+            .do_set_ncn_resolver_program_config_params(
+                &ncn_root,
+                NcnResolverProgramConfigParams {
+                    resolver_change_delay: Some(50),
+                    resolver_share_bps: Some(3_000),
+                    treasury: Some(treasury),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
+            .get_account(
+                &NcnResolverProgramConfig::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(ncn_resolver_program_config.resolver_change_delay(), 50);
+        assert_eq!(ncn_resolver_program_config.resolver_share_bps(), 3_000);
+        assert_eq!(ncn_resolver_program_config.treasury, treasury);
+        assert_eq!(ncn_resolver_program_config.veto_duration(), VETO_DURATION);
+    }
+
+    #[tokio::test]
+    async fn test_set_ncn_resolver_program_config_params_wrong_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            vault_config_admin: _,
+            vault_root: _,
+            ncn_root,
+            operator_roots: _,
+            slashers_amounts: _,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let wrong_admin = Keypair::new();
+        resolver_program_client
+            ._airdrop(&wrong_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let test_error = resolver_program_client
+            .set_ncn_resolver_program_config_params(
+                &ncn_root.ncn_pubkey,
+                &wrong_admin,
+                NcnResolverProgramConfigParams {
+                    resolver_change_delay: Some(50),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert_resolver_error(
+            test_error,
+            ResolverError::NcnResolverProgramConfigAdminInvalid,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_ncn_resolver_program_config_params_resolver_share_bps_invalid_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            vault_config_admin: _,
+            vault_root: _,
+            ncn_root,
+            operator_roots: _,
+            slashers_amounts: _,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let test_error = resolver_program_client
+            .do_set_ncn_resolver_program_config_params(
+                &ncn_root,
+                NcnResolverProgramConfigParams {
+                    resolver_share_bps: Some(10_001),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert_resolver_error(test_error, ResolverError::ResolverShareBpsInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_set_ncn_resolver_program_config_params_resolver_change_delay_too_short_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        // Dropping the delay would let the resolver admin swap resolvers without a timelock
+        let test_error = resolver_program_client
+            .do_set_ncn_resolver_program_config_params(
+                &ncn_root,
+                NcnResolverProgramConfigParams {
+                    resolver_change_delay: Some(
+                        NcnResolverProgramConfig::MIN_RESOLVER_CHANGE_DELAY - 1,
+                    ),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert_resolver_error(test_error, ResolverError::ResolverChangeDelayTooShort);
+    }
+
+    #[tokio::test]
+    async fn test_set_ncn_resolver_program_config_params_re_veto_window_too_short_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        // A zero window would reopen the veto with a deadline of the current slot
+        let test_error = resolver_program_client
+            .do_set_ncn_resolver_program_config_params(
+                &ncn_root,
+                NcnResolverProgramConfigParams {
+                    re_veto_window_slots: Some(
+                        NcnResolverProgramConfig::MIN_RE_VETO_WINDOW_SLOTS - 1,
+                    ),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert_resolver_error(test_error, ResolverError::ReVetoWindowTooShort);
+    }
+
+    #[tokio::test]
+    async fn test_set_ncn_resolver_program_config_params_execution_delay_too_short_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        // Without a delay, a slash could execute in the slot its veto window closes
+        let test_error = resolver_program_client
+            .do_set_ncn_resolver_program_config_params(
+                &ncn_root,
+                NcnResolverProgramConfigParams {
+                    execution_delay_slots: Some(
+                        NcnResolverProgramConfig::MIN_EXECUTION_DELAY_SLOTS - 1,
+                    ),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert_resolver_error(test_error, ResolverError::ExecutionDelayTooShort);
+    }
+
+    #[tokio::test]
+    async fn test_set_ncn_resolver_program_config_params_veto_extension_too_short_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        // A zero extension would use up an extension without moving the veto deadline
+        let test_error = resolver_program_client
+            .do_set_ncn_resolver_program_config_params(
+                &ncn_root,
+                NcnResolverProgramConfigParams {
+                    veto_extension_slots: Some(
+                        NcnResolverProgramConfig::MIN_VETO_EXTENSION_SLOTS - 1,
+                    ),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert_resolver_error(test_error, ResolverError::VetoExtensionTooShort);
+    }
+}
@@ -1,14 +1,20 @@
 #[cfg(test)]
 mod tests {
     use resolver_core::{
-        ncn_slash_proposal_ticket::NcnSlashProposalTicket, slash_proposal::SlashProposal,
+        config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
+        ncn_slash_proposal_ticket::NcnSlashProposalTicket, resolver::Resolver,
+        slash_proposal::SlashProposal,
     };
+    use resolver_sdk::error::ResolverError;
     use solana_program::pubkey::Pubkey;
     use solana_sdk::{signature::Keypair, signer::Signer};
 
     use crate::{
-        fixtures::fixture::{ConfiguredVault, TestBuilder},
-        resolver::MAX_SLASH_AMOUNT,
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::{DELETE_SLASH_PROPOSAL_DURATION, MAX_SLASH_AMOUNT, VETO_DURATION},
     };
 
     #[tokio::test]
@@ -26,7 +32,7 @@ mod tests {
             vault_program_client: _,
             restaking_program_client: _,
             vault_config_admin: _,
-            vault_root: _,
+            vault_root,
             ncn_root,
             operator_roots,
             slashers_amounts,
@@ -44,7 +50,11 @@ mod tests {
 
         let slasher_root = &slashers_amounts[0].0;
 
-        resolver_program_client
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+        let new_resolver_root = resolver_program_client
             .do_initialize_resolver(&ncn_root)
             .await
             .unwrap();
@@ -54,6 +64,7 @@ mod tests {
                 &ncn_root.ncn_pubkey,
                 &operator_roots[0].operator_pubkey,
                 &slasher_root,
+                &vault_root.vault_pubkey,
                 100,
             )
             .await
@@ -80,13 +91,37 @@ mod tests {
 
         assert_eq!(ncn_slash_proposal_ticket.resolver, Pubkey::default());
 
-        let new_resolver = Keypair::new();
+        // The first resolver takes effect immediately
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+        let ncn_slash_proposal_ticket: NcnSlashProposalTicket = resolver_program_client
+            .get_account(&ncn_slash_proposal_ticket_pubkey)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            ncn_slash_proposal_ticket.resolver,
+            resolver_root.resolver_pubkey
+        );
+        assert_eq!(
+            ncn_slash_proposal_ticket.pending_resolver,
+            Pubkey::default()
+        );
+
+        // Replacing it is timelocked
         resolver_program_client
             .do_set_resolver(
                 &ncn_root,
                 &operator_roots[0].operator_pubkey,
                 &slasher_root,
-                &new_resolver.pubkey(),
+                &new_resolver_root.resolver_pubkey,
             )
             .await
             .unwrap();
@@ -95,6 +130,162 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(ncn_slash_proposal_ticket.resolver, new_resolver.pubkey());
+        assert_eq!(
+            ncn_slash_proposal_ticket.resolver,
+            resolver_root.resolver_pubkey
+        );
+        assert_eq!(
+            ncn_slash_proposal_ticket.pending_resolver,
+            new_resolver_root.resolver_pubkey
+        );
+        assert_eq!(
+            ncn_slash_proposal_ticket.pending_resolver_effective_slot(),
+            fixture.get_current_slot().await.unwrap()
+                + NcnResolverProgramConfig::MIN_RESOLVER_CHANGE_DELAY
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_resolver_operator_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_root = &operator_roots[0];
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        // A resolver administered by the operator's admin
+        let resolver_base = Keypair::new();
+        let resolver_pubkey =
+            Resolver::find_program_address(&resolver_program::id(), &resolver_base.pubkey()).0;
+        resolver_program_client
+            .initialize_resolver(
+                &ncn_root.ncn_pubkey,
+                &resolver_pubkey,
+                &operator_root.operator_admin,
+                &resolver_base,
+            )
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &resolver_pubkey,
+            )
+            .await;
+        assert_resolver_error(result, ResolverError::ResolverIsOperator);
+    }
+
+    #[tokio::test]
+    async fn test_set_resolver_ncn_mismatch_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            mut restaking_program_client,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        // A resolver registered under another NCN
+        let other_ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        resolver_program_client
+            .do_initialize_ncn_resolver_program_config(
+                &Config::find_program_address(&resolver_program::id()).0,
+                &other_ncn_root.ncn_pubkey,
+                &other_ncn_root.ncn_admin,
+                VETO_DURATION,
+                DELETE_SLASH_PROPOSAL_DURATION,
+            )
+            .await
+            .unwrap();
+        let other_resolver_root = resolver_program_client
+            .do_initialize_resolver(&other_ncn_root)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &other_resolver_root.resolver_pubkey,
+            )
+            .await;
+
+        assert_resolver_error(result, ResolverError::ResolverNcnMismatch);
     }
 }
@@ -98,6 +98,7 @@ mod tests {
                     ),
                     &bob,
                     &token_program_id,
+                    MINT_AMOUNT,
                 )
                 .await
                 .unwrap();
@@ -106,7 +107,7 @@ mod tests {
             let token_account_acc = fixture.get_token_account(&ata).await.unwrap();
 
             assert_eq!(token_account_acc.delegate, COption::Some(bob));
-            assert_eq!(token_account_acc.delegated_amount, u64::MAX);
+            assert_eq!(token_account_acc.delegated_amount, MINT_AMOUNT);
         } else {
             resolver_program_client
                 .slasher_delegate_token_account(
@@ -116,6 +117,7 @@ mod tests {
                     &slasher_token_account.pubkey(),
                     &bob,
                     &token_program_id,
+                    MINT_AMOUNT,
                 )
                 .await
                 .unwrap();
@@ -126,7 +128,7 @@ mod tests {
                 .unwrap();
 
             assert_eq!(vault_token_acc.delegate, COption::Some(bob));
-            assert_eq!(vault_token_acc.delegated_amount, u64::MAX);
+            assert_eq!(vault_token_acc.delegated_amount, MINT_AMOUNT);
         }
     }
 }
@@ -56,5 +56,8 @@ mod tests {
             .unwrap();
 
         assert_eq!(slasher.admin, new_admin.pubkey());
+        assert_eq!(slasher.delegate_admin, new_admin.pubkey());
+        assert_eq!(slasher.propose_admin, new_admin.pubkey());
+        assert_eq!(slasher.execute_admin, new_admin.pubkey());
     }
 }
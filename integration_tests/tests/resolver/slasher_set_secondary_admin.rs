@@ -1,11 +1,18 @@
 #[cfg(test)]
 mod tests {
+    use borsh::{BorshDeserialize, BorshSerialize};
     use resolver_core::slasher::Slasher;
-    use resolver_sdk::instruction::SlasherAdminRole;
+    use resolver_sdk::{
+        error::ResolverError,
+        instruction::{ResolverInstruction, SlasherAdminRole},
+    };
     use solana_sdk::{signature::Keypair, signer::Signer};
 
     use crate::{
-        fixtures::fixture::{ConfiguredVault, TestBuilder},
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::{assert_resolver_error, SlasherRoot},
+        },
         resolver::MAX_SLASH_AMOUNT,
     };
 
@@ -58,5 +65,143 @@ mod tests {
             .unwrap();
 
         assert_eq!(slasher.delegate_admin, new_admin.pubkey());
+        assert_eq!(slasher.propose_admin, slasher_root.slasher_admin.pubkey());
+        assert_eq!(slasher.execute_admin, slasher_root.slasher_admin.pubkey());
+
+        let propose_admin = Keypair::new();
+        resolver_program_client
+            .slasher_set_secondary_admin(
+                &slasher_root.slasher_pubkey,
+                &slasher_root.slasher_admin,
+                &propose_admin,
+                SlasherAdminRole::ProposeAdmin,
+            )
+            .await
+            .unwrap();
+
+        let execute_admin = Keypair::new();
+        resolver_program_client
+            .slasher_set_secondary_admin(
+                &slasher_root.slasher_pubkey,
+                &slasher_root.slasher_admin,
+                &execute_admin,
+                SlasherAdminRole::ExecuteAdmin,
+            )
+            .await
+            .unwrap();
+
+        let slasher: Slasher = resolver_program_client
+            .get_account(&slasher_root.slasher_pubkey)
+            .await
+            .unwrap();
+
+        assert_eq!(slasher.delegate_admin, new_admin.pubkey());
+        assert_eq!(slasher.propose_admin, propose_admin.pubkey());
+        assert_eq!(slasher.execute_admin, execute_admin.pubkey());
+    }
+
+    #[tokio::test]
+    async fn test_delegate_admin_cannot_propose_slash() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let delegate_admin = Keypair::new();
+        let propose_admin = Keypair::new();
+        fixture
+            .transfer(&delegate_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+        fixture
+            .transfer(&propose_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .slasher_set_secondary_admin(
+                &slasher_root.slasher_pubkey,
+                &slasher_root.slasher_admin,
+                &delegate_admin,
+                SlasherAdminRole::DelegateAdmin,
+            )
+            .await
+            .unwrap();
+        resolver_program_client
+            .slasher_set_secondary_admin(
+                &slasher_root.slasher_pubkey,
+                &slasher_root.slasher_admin,
+                &propose_admin,
+                SlasherAdminRole::ProposeAdmin,
+            )
+            .await
+            .unwrap();
+
+        let delegate_admin_root = SlasherRoot {
+            slasher_pubkey: slasher_root.slasher_pubkey,
+            slasher_admin: delegate_admin,
+        };
+        let test_result = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &delegate_admin_root,
+                &vault_root.vault_pubkey,
+                MAX_SLASH_AMOUNT,
+            )
+            .await;
+        assert_resolver_error(test_result, ResolverError::SlasherProposeAdminInvalid);
+
+        let propose_admin_root = SlasherRoot {
+            slasher_pubkey: slasher_root.slasher_pubkey,
+            slasher_admin: propose_admin,
+        };
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &propose_admin_root,
+                &vault_root.vault_pubkey,
+                MAX_SLASH_AMOUNT,
+            )
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn test_slasher_set_secondary_admin_unknown_role_fails() {
+        let mut data =
+            ResolverInstruction::SlasherSetSecondaryAdmin(SlasherAdminRole::ExecuteAdmin)
+                .try_to_vec()
+                .unwrap();
+        // One past the last role, rejected when the entrypoint deserializes the instruction
+        *data.last_mut().unwrap() += 1;
+
+        assert!(ResolverInstruction::try_from_slice(&data).is_err());
     }
 }
@@ -1,10 +1,15 @@
 #[cfg(test)]
 mod tests {
-    use resolver_core::slash_proposal::SlashProposal;
+    use resolver_core::{slash_proposal::SlashProposal, slash_proposal_list::SlashProposalList};
+    use resolver_sdk::error::ResolverError;
+    use solana_program::pubkey::Pubkey;
 
     use crate::{
-        fixtures::fixture::{ConfiguredVault, TestBuilder},
-        resolver::MAX_SLASH_AMOUNT,
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::{assert_resolver_error, NcnResolverProgramConfigParams},
+        },
+        resolver::{MAX_SLASH_AMOUNT, VETO_DURATION},
     };
 
     #[tokio::test]
@@ -22,7 +27,7 @@ mod tests {
             vault_program_client: _,
             restaking_program_client: _,
             vault_config_admin: _,
-            vault_root: _,
+            vault_root,
             ncn_root,
             operator_roots,
             slashers_amounts,
@@ -50,6 +55,7 @@ mod tests {
                 &ncn_root.ncn_pubkey,
                 &operator_roots[0].operator_pubkey,
                 &slasher_root,
+                &vault_root.vault_pubkey,
                 100,
             )
             .await
@@ -71,6 +77,8 @@ mod tests {
         assert_eq!(slash_proposal.slasher, slasher_root.slasher_pubkey);
         assert_eq!(slash_proposal.amount(), 100);
         assert!(!slash_proposal.completed());
+        assert_eq!(slash_proposal.veto_resolver, Pubkey::default());
+        assert_eq!(slash_proposal.veto_reason(), &[0; 128]);
 
         resolver_program_client
             .do_set_resolver(
@@ -88,6 +96,7 @@ mod tests {
                 &operator_roots[0].operator_pubkey,
                 &slasher_root,
                 &resolver_root,
+                "operator was not at fault",
             )
             .await
             .unwrap();
@@ -101,5 +110,177 @@ mod tests {
         assert_eq!(slash_proposal.slasher, slasher_root.slasher_pubkey);
         assert_eq!(slash_proposal.amount(), 100);
         assert!(slash_proposal.completed());
+        assert_eq!(slash_proposal.veto_resolver, resolver_root.resolver_pubkey);
+        let reason = b"operator was not at fault";
+        assert_eq!(&slash_proposal.veto_reason()[..reason.len()], reason);
+        assert!(slash_proposal.veto_reason()[reason.len()..]
+            .iter()
+            .all(|b| *b == 0));
+
+        // The vetoed amount no longer counts against the operator's stake
+        let slash_proposal_list: SlashProposalList = resolver_program_client
+            .get_account(
+                &SlashProposalList::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    &operator_roots[0].operator_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(slash_proposal_list.outstanding_slash_amount(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_veto_slash_resolver_change_delay_longer_than_veto_duration_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        // A timelocked first assignment would only land after the veto window closed
+        resolver_program_client
+            .do_set_ncn_resolver_program_config_params(
+                &ncn_root,
+                NcnResolverProgramConfigParams {
+                    resolver_change_delay: Some(2 * VETO_DURATION),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &resolver_root,
+                "",
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(
+                &SlashProposal::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    &operator_pubkey,
+                    &slasher_root.slasher_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert!(slash_proposal.completed());
+        assert_eq!(slash_proposal.veto_resolver, resolver_root.resolver_pubkey);
+    }
+
+    #[tokio::test]
+    async fn test_veto_slash_resolver_is_operator_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            mut restaking_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_root = &operator_roots[0];
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        // The resolver's admin takes over the operator after the assignment
+        restaking_program_client
+            .operator_set_admin(
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                &resolver_root.resolver_admin,
+            )
+            .await
+            .unwrap();
+
+        let test_error = resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &resolver_root,
+                "",
+            )
+            .await;
+        assert_resolver_error(test_error, ResolverError::ResolverIsOperator);
     }
 }
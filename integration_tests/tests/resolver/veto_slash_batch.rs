@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::slash_proposal::SlashProposal;
+
+    use crate::{
+        fixtures::fixture::{ConfiguredVault, TestBuilder},
+        resolver::MAX_SLASH_AMOUNT,
+    };
+
+    #[tokio::test]
+    async fn test_veto_slash_batch_skips_completed_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 3;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operators: Vec<_> = operator_roots
+            .iter()
+            .map(|operator_root| operator_root.operator_pubkey)
+            .collect();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        for operator in operators.iter() {
+            resolver_program_client
+                .do_propose_slash(
+                    &ncn_root.ncn_pubkey,
+                    operator,
+                    slasher_root,
+                    &vault_root.vault_pubkey,
+                    100,
+                )
+                .await
+                .unwrap();
+            resolver_program_client
+                .do_set_resolver(
+                    &ncn_root,
+                    operator,
+                    slasher_root,
+                    &resolver_root.resolver_pubkey,
+                )
+                .await
+                .unwrap();
+        }
+
+        // The first proposal is already vetoed on its own
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operators[0],
+                slasher_root,
+                &resolver_root,
+                "first",
+            )
+            .await
+            .unwrap();
+
+        let vetoed_count = resolver_program_client
+            .do_veto_slash_batch(
+                &ncn_root.ncn_pubkey,
+                &operators,
+                slasher_root,
+                &resolver_root,
+                "false alarm",
+            )
+            .await
+            .unwrap();
+        assert_eq!(vetoed_count, 2);
+
+        for (index, operator) in operators.iter().enumerate() {
+            let slash_proposal: SlashProposal = resolver_program_client
+                .get_account(
+                    &SlashProposal::find_program_address(
+                        &resolver_program::id(),
+                        &ncn_root.ncn_pubkey,
+                        operator,
+                        &slasher_root.slasher_pubkey,
+                    )
+                    .0,
+                )
+                .await
+                .unwrap();
+
+            assert!(slash_proposal.completed());
+            assert_eq!(slash_proposal.veto_resolver, resolver_root.resolver_pubkey);
+            let reason: &[u8] = if index == 0 { b"first" } else { b"false alarm" };
+            assert_eq!(&slash_proposal.veto_reason()[..reason.len()], reason);
+        }
+    }
+}
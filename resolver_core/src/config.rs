@@ -1,5 +1,6 @@
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use resolver_sdk::error::ResolverError;
 use shank::ShankAccount;
 use solana_program::{
     account_info::AccountInfo, epoch_schedule::DEFAULT_SLOTS_PER_EPOCH, msg,
@@ -55,6 +56,15 @@ impl Config {
         self.epoch_length.into()
     }
 
+    pub fn check_admin(&self, admin: &Pubkey) -> Result<(), ResolverError> {
+        if self.admin.ne(admin) {
+            msg!("Admin is not the config admin");
+            return Err(ResolverError::ConfigAdminInvalid);
+        }
+
+        Ok(())
+    }
+
     /// Returns the seeds for the PDA
     pub fn seeds() -> Vec<Vec<u8>> {
         vec![b"config".to_vec()]
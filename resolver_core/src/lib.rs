@@ -1,6 +1,8 @@
 pub mod config;
 pub mod ncn_resolver_program_config;
 pub mod ncn_slash_proposal_ticket;
+pub mod ncn_slash_stats;
 pub mod resolver;
 pub mod slash_proposal;
+pub mod slash_proposal_list;
 pub mod slasher;
@@ -1,8 +1,14 @@
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_bytemuck::{
+    types::{PodU16, PodU64},
+    AccountDeserialize, Discriminator,
+};
+use resolver_sdk::error::ResolverError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
+use crate::slash_proposal::SlashProposal;
+
 /// The global configuration account for the resolver program. Manages
 /// program-wide settings and state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
@@ -18,6 +24,44 @@ pub struct NcnResolverProgramConfig {
 
     resolver_count: PodU64,
 
+    /// The number of slots a resolver change waits before it can be applied
+    resolver_change_delay: PodU64,
+
+    /// The owner of the token account receiving the treasury's share of slashed funds
+    pub treasury: Pubkey,
+
+    /// The share of slashed funds paid to the resolver, in basis points
+    resolver_share_bps: PodU16,
+
+    /// The number of slots a contested veto extends the veto deadline by
+    veto_extension_slots: PodU64,
+
+    /// The maximum number of times a slash proposal's veto deadline can be extended
+    max_veto_extensions: PodU64,
+
+    /// The number of operators in the blacklist
+    blacklist_len: PodU64,
+
+    /// Operators that can't be slashed through the resolver program
+    blacklist: [Pubkey; 16],
+
+    /// The number of distinct approvals a slash proposal needs before it can be executed
+    approval_threshold: PodU64,
+
+    /// The number of slots after the veto window closes before a slash can be executed
+    execution_delay_slots: PodU64,
+
+    /// The number of destinations in the slash destination allowlist
+    slash_destinations_len: PodU64,
+
+    /// Owners of token accounts, besides the treasury, that slash proposals can route slashed
+    /// funds to
+    slash_destinations: [Pubkey; 8],
+
+    /// The number of slots the veto window is reopened for after an execution attempt that
+    /// could not slash
+    re_veto_window_slots: PodU64,
+
     /// The bump seed for the PDA
     pub bump: u8,
 }
@@ -27,6 +71,28 @@ impl Discriminator for NcnResolverProgramConfig {
 }
 
 impl NcnResolverProgramConfig {
+    pub const MAX_BPS: u16 = 10_000;
+
+    pub const MAX_BLACKLIST_LEN: usize = 16;
+
+    pub const MAX_SLASH_DESTINATIONS: usize = 8;
+
+    /// The shortest resolver change delay an NCN can configure, so the resolver admin can't
+    /// remove the timelock before swapping a resolver
+    pub const MIN_RESOLVER_CHANGE_DELAY: u64 = 10;
+
+    /// The shortest veto extension an NCN can configure, so contesting a veto always moves the
+    /// veto deadline
+    pub const MIN_VETO_EXTENSION_SLOTS: u64 = 10;
+
+    /// The shortest re-veto window an NCN can configure, so resolvers get at least this many
+    /// slots to veto after a failed execution attempt
+    pub const MIN_RE_VETO_WINDOW_SLOTS: u64 = 10;
+
+    /// The shortest execution delay an NCN can configure, so watchers always get a cooldown
+    /// between the veto deadline and execution
+    pub const MIN_EXECUTION_DELAY_SLOTS: u64 = 10;
+
     pub fn new(
         resolver_admin: Pubkey,
         veto_duration: u64,
@@ -38,6 +104,18 @@ impl NcnResolverProgramConfig {
             veto_duration: PodU64::from(veto_duration),
             delete_slash_proposal_duration: PodU64::from(delete_slash_proposal_duration),
             resolver_count: PodU64::from(0),
+            resolver_change_delay: PodU64::from(Self::MIN_RESOLVER_CHANGE_DELAY),
+            treasury: resolver_admin,
+            resolver_share_bps: PodU16::from(0),
+            veto_extension_slots: PodU64::from(Self::MIN_VETO_EXTENSION_SLOTS),
+            max_veto_extensions: PodU64::from(0),
+            blacklist_len: PodU64::from(0),
+            blacklist: [Pubkey::default(); Self::MAX_BLACKLIST_LEN],
+            approval_threshold: PodU64::from(0),
+            execution_delay_slots: PodU64::from(Self::MIN_EXECUTION_DELAY_SLOTS),
+            slash_destinations_len: PodU64::from(0),
+            slash_destinations: [Pubkey::default(); Self::MAX_SLASH_DESTINATIONS],
+            re_veto_window_slots: PodU64::from(Self::MIN_RE_VETO_WINDOW_SLOTS),
             bump,
         }
     }
@@ -60,6 +138,315 @@ impl NcnResolverProgramConfig {
         self.resolver_count = PodU64::from(count);
     }
 
+    pub fn resolver_change_delay(&self) -> u64 {
+        self.resolver_change_delay.into()
+    }
+
+    pub fn set_resolver_change_delay(
+        &mut self,
+        resolver_change_delay: u64,
+    ) -> Result<(), ResolverError> {
+        if resolver_change_delay < Self::MIN_RESOLVER_CHANGE_DELAY {
+            msg!(
+                "Resolver change delay {} is below the minimum {}",
+                resolver_change_delay,
+                Self::MIN_RESOLVER_CHANGE_DELAY
+            );
+            return Err(ResolverError::ResolverChangeDelayTooShort);
+        }
+        self.resolver_change_delay = PodU64::from(resolver_change_delay);
+
+        Ok(())
+    }
+
+    pub fn resolver_share_bps(&self) -> u16 {
+        self.resolver_share_bps.into()
+    }
+
+    pub fn set_resolver_share_bps(&mut self, resolver_share_bps: u16) -> Result<(), ResolverError> {
+        if resolver_share_bps > Self::MAX_BPS {
+            msg!(
+                "Resolver share bps {} exceeds {}",
+                resolver_share_bps,
+                Self::MAX_BPS
+            );
+            return Err(ResolverError::ResolverShareBpsInvalid);
+        }
+        self.resolver_share_bps = PodU16::from(resolver_share_bps);
+
+        Ok(())
+    }
+
+    /// Splits a slashed amount into the resolver's share and the treasury's share.
+    /// The resolver's share is rounded down so the treasury receives any remainder.
+    ///
+    /// # Arguments
+    /// * `amount` - The slashed amount
+    ///
+    /// # Returns
+    /// * `Result<(u64, u64), ResolverError>` - The resolver amount and the treasury amount
+    pub fn split_slash_amount(&self, amount: u64) -> Result<(u64, u64), ResolverError> {
+        let resolver_amount: u64 = (amount as u128)
+            .checked_mul(self.resolver_share_bps() as u128)
+            .and_then(|product| product.checked_div(Self::MAX_BPS as u128))
+            .and_then(|quotient| quotient.try_into().ok())
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+        let treasury_amount = amount
+            .checked_sub(resolver_amount)
+            .ok_or(ResolverError::ArithmeticUnderflow)?;
+
+        Ok((resolver_amount, treasury_amount))
+    }
+
+    pub fn veto_extension_slots(&self) -> u64 {
+        self.veto_extension_slots.into()
+    }
+
+    pub fn set_veto_extension_slots(
+        &mut self,
+        veto_extension_slots: u64,
+    ) -> Result<(), ResolverError> {
+        if veto_extension_slots < Self::MIN_VETO_EXTENSION_SLOTS {
+            msg!(
+                "Veto extension slots {} is below the minimum {}",
+                veto_extension_slots,
+                Self::MIN_VETO_EXTENSION_SLOTS
+            );
+            return Err(ResolverError::VetoExtensionTooShort);
+        }
+        self.veto_extension_slots = PodU64::from(veto_extension_slots);
+
+        Ok(())
+    }
+
+    pub fn max_veto_extensions(&self) -> u64 {
+        self.max_veto_extensions.into()
+    }
+
+    pub fn set_max_veto_extensions(&mut self, max_veto_extensions: u64) {
+        self.max_veto_extensions = PodU64::from(max_veto_extensions);
+    }
+
+    pub fn blacklist_len(&self) -> u64 {
+        self.blacklist_len.into()
+    }
+
+    /// Returns the blacklisted operators
+    pub fn blacklist(&self) -> &[Pubkey] {
+        &self.blacklist[..self.blacklist_len() as usize]
+    }
+
+    pub fn is_blacklisted(&self, operator: &Pubkey) -> bool {
+        self.blacklist().contains(operator)
+    }
+
+    /// Adds `operator` to the blacklist
+    ///
+    /// # Arguments
+    /// * `operator` - The operator account
+    pub fn add_to_blacklist(&mut self, operator: Pubkey) -> Result<(), ResolverError> {
+        if self.is_blacklisted(&operator) {
+            msg!("Operator {} is already blacklisted", operator);
+            return Err(ResolverError::OperatorAlreadyBlacklisted);
+        }
+
+        let len = self.blacklist_len() as usize;
+        if len >= Self::MAX_BLACKLIST_LEN {
+            msg!("Blacklist is full");
+            return Err(ResolverError::BlacklistFull);
+        }
+
+        self.blacklist[len] = operator;
+        self.blacklist_len = PodU64::from(len as u64 + 1);
+
+        Ok(())
+    }
+
+    /// Removes `operator` from the blacklist, moving the last entry into its slot
+    ///
+    /// # Arguments
+    /// * `operator` - The operator account
+    pub fn remove_from_blacklist(&mut self, operator: &Pubkey) -> Result<(), ResolverError> {
+        let index = self
+            .blacklist()
+            .iter()
+            .position(|entry| entry.eq(operator))
+            .ok_or_else(|| {
+                msg!("Operator {} is not blacklisted", operator);
+                ResolverError::OperatorNotBlacklisted
+            })?;
+
+        let last = self.blacklist_len() as usize - 1;
+        self.blacklist[index] = self.blacklist[last];
+        self.blacklist[last] = Pubkey::default();
+        self.blacklist_len = PodU64::from(last as u64);
+
+        Ok(())
+    }
+
+    pub fn check_operator_not_blacklisted(&self, operator: &Pubkey) -> Result<(), ResolverError> {
+        if self.is_blacklisted(operator) {
+            msg!("Operator {} is blacklisted", operator);
+            return Err(ResolverError::OperatorBlacklisted);
+        }
+
+        Ok(())
+    }
+
+    pub fn approval_threshold(&self) -> u64 {
+        self.approval_threshold.into()
+    }
+
+    pub fn set_approval_threshold(&mut self, approval_threshold: u64) -> Result<(), ResolverError> {
+        if approval_threshold > SlashProposal::MAX_APPROVALS as u64 {
+            msg!(
+                "Approval threshold {} exceeds {}",
+                approval_threshold,
+                SlashProposal::MAX_APPROVALS
+            );
+            return Err(ResolverError::ApprovalThresholdInvalid);
+        }
+        self.approval_threshold = PodU64::from(approval_threshold);
+
+        Ok(())
+    }
+
+    pub fn execution_delay_slots(&self) -> u64 {
+        self.execution_delay_slots.into()
+    }
+
+    pub fn set_execution_delay_slots(
+        &mut self,
+        execution_delay_slots: u64,
+    ) -> Result<(), ResolverError> {
+        if execution_delay_slots < Self::MIN_EXECUTION_DELAY_SLOTS {
+            msg!(
+                "Execution delay slots {} is below the minimum {}",
+                execution_delay_slots,
+                Self::MIN_EXECUTION_DELAY_SLOTS
+            );
+            return Err(ResolverError::ExecutionDelayTooShort);
+        }
+        self.execution_delay_slots = PodU64::from(execution_delay_slots);
+
+        Ok(())
+    }
+
+    pub fn re_veto_window_slots(&self) -> u64 {
+        self.re_veto_window_slots.into()
+    }
+
+    pub fn set_re_veto_window_slots(
+        &mut self,
+        re_veto_window_slots: u64,
+    ) -> Result<(), ResolverError> {
+        if re_veto_window_slots < Self::MIN_RE_VETO_WINDOW_SLOTS {
+            msg!(
+                "Re-veto window slots {} is below the minimum {}",
+                re_veto_window_slots,
+                Self::MIN_RE_VETO_WINDOW_SLOTS
+            );
+            return Err(ResolverError::ReVetoWindowTooShort);
+        }
+        self.re_veto_window_slots = PodU64::from(re_veto_window_slots);
+
+        Ok(())
+    }
+
+    pub fn slash_destinations_len(&self) -> u64 {
+        self.slash_destinations_len.into()
+    }
+
+    /// Returns the allowed slash destinations
+    pub fn slash_destinations(&self) -> &[Pubkey] {
+        &self.slash_destinations[..self.slash_destinations_len() as usize]
+    }
+
+    pub fn is_slash_destination_allowed(&self, slash_destination: &Pubkey) -> bool {
+        self.slash_destinations().contains(slash_destination)
+    }
+
+    /// Adds `slash_destination` to the slash destination allowlist
+    ///
+    /// # Arguments
+    /// * `slash_destination` - The owner of the token account receiving slashed funds
+    pub fn add_slash_destination(
+        &mut self,
+        slash_destination: Pubkey,
+    ) -> Result<(), ResolverError> {
+        if self.is_slash_destination_allowed(&slash_destination) {
+            msg!("Slash destination {} is already allowed", slash_destination);
+            return Err(ResolverError::SlashDestinationAlreadyAllowed);
+        }
+
+        let len = self.slash_destinations_len() as usize;
+        if len >= Self::MAX_SLASH_DESTINATIONS {
+            msg!("Slash destination allowlist is full");
+            return Err(ResolverError::SlashDestinationAllowlistFull);
+        }
+
+        self.slash_destinations[len] = slash_destination;
+        self.slash_destinations_len = PodU64::from(len as u64 + 1);
+
+        Ok(())
+    }
+
+    /// Removes `slash_destination` from the slash destination allowlist, moving the last entry
+    /// into its slot
+    ///
+    /// # Arguments
+    /// * `slash_destination` - The owner of the token account receiving slashed funds
+    pub fn remove_slash_destination(
+        &mut self,
+        slash_destination: &Pubkey,
+    ) -> Result<(), ResolverError> {
+        let index = self
+            .slash_destinations()
+            .iter()
+            .position(|entry| entry.eq(slash_destination))
+            .ok_or_else(|| {
+                msg!("Slash destination {} is not allowed", slash_destination);
+                ResolverError::InvalidSlashDestination
+            })?;
+
+        let last = self.slash_destinations_len() as usize - 1;
+        self.slash_destinations[index] = self.slash_destinations[last];
+        self.slash_destinations[last] = Pubkey::default();
+        self.slash_destinations_len = PodU64::from(last as u64);
+
+        Ok(())
+    }
+
+    /// Resolves a slash proposal's destination to the owner of the token account that receives
+    /// the slashed funds not paid to the resolver. The default destination is the treasury, any
+    /// other destination must be on the allowlist.
+    ///
+    /// # Arguments
+    /// * `slash_destination` - The slash destination stored on the slash proposal
+    pub fn slash_destination_owner(
+        &self,
+        slash_destination: &Pubkey,
+    ) -> Result<Pubkey, ResolverError> {
+        if slash_destination.eq(&Pubkey::default()) {
+            return Ok(self.treasury);
+        }
+        if !self.is_slash_destination_allowed(slash_destination) {
+            msg!("Slash destination {} is not allowed", slash_destination);
+            return Err(ResolverError::InvalidSlashDestination);
+        }
+
+        Ok(*slash_destination)
+    }
+
+    pub fn check_resolver_admin(&self, resolver_admin: &Pubkey) -> Result<(), ResolverError> {
+        if self.resolver_admin.ne(resolver_admin) {
+            msg!("NcnResolverProgramConfig resolver admin is incorrect");
+            return Err(ResolverError::NcnResolverProgramConfigAdminInvalid);
+        }
+
+        Ok(())
+    }
+
     pub fn seeds(ncn: &Pubkey) -> Vec<Vec<u8>> {
         Vec::from_iter([
             b"ncn_resolver_program_config".to_vec(),
@@ -1,5 +1,5 @@
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
 use resolver_sdk::error::ResolverError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
@@ -16,6 +16,12 @@ pub struct NcnSlashProposalTicket {
 
     pub resolver: Pubkey,
 
+    /// The resolver that replaces `resolver` once the timelock has elapsed
+    pub pending_resolver: Pubkey,
+
+    /// The slot at which `pending_resolver` can be applied
+    pending_resolver_effective_slot: PodU64,
+
     /// The bump seed for the PDA
     pub bump: u8,
     // Reserved space
@@ -34,6 +40,8 @@ impl NcnSlashProposalTicket {
             ncn,
             slash_proposal,
             resolver: Pubkey::default(),
+            pending_resolver: Pubkey::default(),
+            pending_resolver_effective_slot: PodU64::from(0),
             bump,
             // reserved: [0; 263],
         }
@@ -43,6 +51,67 @@ impl NcnSlashProposalTicket {
         self.resolver = new_resolver;
     }
 
+    /// Immediately replaces the resolver, bypassing the change timelock and discarding any
+    /// pending change. The resolver is not part of any PDA seeds, so the slash proposal and
+    /// this ticket keep their addresses and deadlines.
+    pub fn reassign_resolver(&mut self, new_resolver: Pubkey) {
+        self.resolver = new_resolver;
+        self.clear_pending_resolver();
+    }
+
+    pub fn pending_resolver_effective_slot(&self) -> u64 {
+        self.pending_resolver_effective_slot.into()
+    }
+
+    /// Schedules `new_resolver` to replace the current resolver at `effective_slot`,
+    /// overwriting any change that is already pending
+    pub fn set_pending_resolver(&mut self, new_resolver: Pubkey, effective_slot: u64) {
+        self.pending_resolver = new_resolver;
+        self.pending_resolver_effective_slot = PodU64::from(effective_slot);
+    }
+
+    /// Replaces the resolver with the pending resolver once its effective slot is reached
+    ///
+    /// # Arguments
+    /// * `current_slot` - The current slot
+    pub fn apply_pending_resolver(&mut self, current_slot: u64) -> Result<(), ResolverError> {
+        self.check_resolver_change_pending()?;
+        if self.pending_resolver_effective_slot() > current_slot {
+            msg!(
+                "Resolver change is not effective until slot {}",
+                self.pending_resolver_effective_slot()
+            );
+            return Err(ResolverError::ResolverChangeTimelockNotElapsed);
+        }
+
+        self.resolver = self.pending_resolver;
+        self.clear_pending_resolver();
+
+        Ok(())
+    }
+
+    /// Discards the pending resolver change
+    pub fn cancel_pending_resolver(&mut self) -> Result<(), ResolverError> {
+        self.check_resolver_change_pending()?;
+        self.clear_pending_resolver();
+
+        Ok(())
+    }
+
+    fn clear_pending_resolver(&mut self) {
+        self.pending_resolver = Pubkey::default();
+        self.pending_resolver_effective_slot = PodU64::from(0);
+    }
+
+    fn check_resolver_change_pending(&self) -> Result<(), ResolverError> {
+        if self.pending_resolver.eq(&Pubkey::default()) {
+            msg!("No resolver change is pending");
+            return Err(ResolverError::ResolverChangeNotPending);
+        }
+
+        Ok(())
+    }
+
     pub fn check_slash_proposal(&self, slash_proposal: &Pubkey) -> Result<(), ResolverError> {
         if self.slash_proposal.ne(slash_proposal) {
             msg!("Slash proposal is incorrect");
@@ -0,0 +1,140 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+/// Aggregate slashing statistics for a NCN, updated as slash proposals are proposed, vetoed and
+/// executed so they can be read without scanning every slash proposal account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct NcnSlashStats {
+    /// The NCN account
+    pub ncn: Pubkey,
+
+    /// The number of slash proposals created
+    total_proposals: PodU64,
+
+    /// The sum of the amounts of all slash proposals created
+    total_amount_proposed: PodU64,
+
+    /// The number of slash proposals executed
+    total_executed: PodU64,
+
+    /// The sum of the amounts of all slash proposals executed
+    total_amount_executed: PodU64,
+
+    /// The number of slash proposals vetoed
+    total_vetoed: PodU64,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+}
+
+impl Discriminator for NcnSlashStats {
+    const DISCRIMINATOR: u8 = 8;
+}
+
+impl NcnSlashStats {
+    pub fn new(ncn: Pubkey, bump: u8) -> Self {
+        Self {
+            ncn,
+            total_proposals: PodU64::from(0),
+            total_amount_proposed: PodU64::from(0),
+            total_executed: PodU64::from(0),
+            total_amount_executed: PodU64::from(0),
+            total_vetoed: PodU64::from(0),
+            bump,
+        }
+    }
+
+    pub fn total_proposals(&self) -> u64 {
+        self.total_proposals.into()
+    }
+
+    pub fn total_amount_proposed(&self) -> u64 {
+        self.total_amount_proposed.into()
+    }
+
+    pub fn total_executed(&self) -> u64 {
+        self.total_executed.into()
+    }
+
+    pub fn total_amount_executed(&self) -> u64 {
+        self.total_amount_executed.into()
+    }
+
+    pub fn total_vetoed(&self) -> u64 {
+        self.total_vetoed.into()
+    }
+
+    /// Records a new slash proposal of `amount`
+    pub fn record_proposal(&mut self, amount: u64) {
+        self.total_proposals = PodU64::from(self.total_proposals().saturating_add(1));
+        self.total_amount_proposed =
+            PodU64::from(self.total_amount_proposed().saturating_add(amount));
+    }
+
+    /// Records an executed slash of `amount`
+    pub fn record_execution(&mut self, amount: u64) {
+        self.total_executed = PodU64::from(self.total_executed().saturating_add(1));
+        self.total_amount_executed =
+            PodU64::from(self.total_amount_executed().saturating_add(amount));
+    }
+
+    /// Records a vetoed slash proposal
+    pub fn record_veto(&mut self) {
+        self.total_vetoed = PodU64::from(self.total_vetoed().saturating_add(1));
+    }
+
+    pub fn seeds(ncn: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([b"ncn_slash_stats".to_vec(), ncn.as_ref().to_vec()])
+    }
+
+    pub fn find_program_address(program_id: &Pubkey, ncn: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(ncn);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the account as an [`NcnSlashStats`] account, returning an error if it is not.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `ncn_slash_stats` - The account to load the NcnSlashStats from
+    /// * `ncn` - The NCN account
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        ncn_slash_stats: &AccountInfo,
+        ncn: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if ncn_slash_stats.owner.ne(program_id) {
+            msg!("NcnSlashStats account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if ncn_slash_stats.data_is_empty() {
+            msg!("NcnSlashStats account data is empty");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if expect_writable && !ncn_slash_stats.is_writable {
+            msg!("NcnSlashStats account is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if ncn_slash_stats.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("NcnSlashStats account discriminator is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let expected_pubkey = Self::find_program_address(program_id, ncn.key).0;
+        if ncn_slash_stats.key.ne(&expected_pubkey) {
+            msg!("NcnSlashStats account is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+}
@@ -16,6 +16,9 @@ pub struct Resolver {
     /// The resolver index
     index: PodU64,
 
+    /// The NCN the resolver is authorized to resolve slash proposals for
+    pub ncn: Pubkey,
+
     /// The bump seed for the PDA
     pub bump: u8,
 }
@@ -25,11 +28,12 @@ impl Discriminator for Resolver {
 }
 
 impl Resolver {
-    pub fn new(base: Pubkey, admin: Pubkey, index: u64, bump: u8) -> Self {
+    pub fn new(base: Pubkey, ncn: Pubkey, admin: Pubkey, index: u64, bump: u8) -> Self {
         Self {
             base,
             admin,
             index: PodU64::from(index),
+            ncn,
             bump,
         }
     }
@@ -47,6 +51,38 @@ impl Resolver {
         Ok(())
     }
 
+    pub fn check_ncn(&self, ncn: &Pubkey) -> Result<(), ResolverError> {
+        if self.ncn.ne(ncn) {
+            msg!("Resolver is authorized for NCN {}, not {}", self.ncn, ncn);
+            return Err(ResolverError::ResolverNcnMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Ensures a resolver, or its admin, is not the operator being slashed or that operator's admin
+    ///
+    /// # Arguments
+    /// * `resolver` - The resolver or resolver admin Pubkey
+    /// * `operator` - The operator account Pubkey
+    /// * `operator_admin` - The operator's admin Pubkey
+    pub fn check_not_operator(
+        resolver: &Pubkey,
+        operator: &Pubkey,
+        operator_admin: &Pubkey,
+    ) -> Result<(), ResolverError> {
+        if resolver.eq(operator) || resolver.eq(operator_admin) {
+            msg!(
+                "Resolver {} is controlled by operator {}",
+                resolver,
+                operator
+            );
+            return Err(ResolverError::ResolverIsOperator);
+        }
+
+        Ok(())
+    }
+
     pub fn seeds(base: &Pubkey) -> Vec<Vec<u8>> {
         Vec::from_iter([b"resolver".to_vec(), base.as_ref().to_vec()])
     }
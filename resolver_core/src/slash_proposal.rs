@@ -1,12 +1,46 @@
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{
-    types::{PodBool, PodU64},
+    types::{PodBool, PodU16, PodU64},
     AccountDeserialize, Discriminator,
 };
 use resolver_sdk::error::ResolverError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
+/// The lifecycle state of a [`SlashProposal`] at a given slot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SlashProposalState {
+    /// The veto window has closed but the slash is waiting on its execution delay or approvals
+    AwaitingExecution = 1,
+    /// The slash proposal can still be vetoed
+    InVetoWindow = 2,
+    /// The slash proposal was vetoed by a resolver
+    Vetoed = 3,
+    /// The slash can be executed
+    Executable = 4,
+    /// The slash was executed
+    Completed = 5,
+    /// The slash proposal was vetoed or executed and can now be deleted
+    Expired = 6,
+}
+
+impl TryFrom<u8> for SlashProposalState {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::AwaitingExecution),
+            2 => Ok(Self::InVetoWindow),
+            3 => Ok(Self::Vetoed),
+            4 => Ok(Self::Executable),
+            5 => Ok(Self::Completed),
+            6 => Ok(Self::Expired),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+}
+
 /// The vault configuration account for the vault program.
 /// Manages program-wide settings and state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
@@ -31,6 +65,48 @@ pub struct SlashProposal {
 
     /// The bump seed for the PDA
     pub bump: u8,
+
+    /// The number of times the veto deadline has been extended
+    extensions_count: PodU64,
+
+    /// The resolver that vetoed the slash proposal
+    pub veto_resolver: Pubkey,
+
+    /// The reason given for the veto, zero-padded UTF-8
+    veto_reason: [u8; 128],
+
+    /// The bps of the operator's stake the slash was proposed as, 0 for absolute proposals
+    slash_bps: PodU16,
+
+    /// The first slash proposal of the batch this proposal was created in, default if it was
+    /// proposed on its own
+    pub batch_leader: Pubkey,
+
+    /// The number of distinct approvals needed before execution, captured from the NCN's config
+    approval_threshold: PodU64,
+
+    /// The number of approvals recorded
+    approvals_count: PodU64,
+
+    /// The signers that approved the slash proposal
+    approvals: [Pubkey; 3],
+
+    /// The number of slots after the veto deadline before the slash can be executed, captured
+    /// from the NCN's config
+    execution_delay_slots: PodU64,
+
+    /// The owner of the token account receiving the slashed funds not paid to the resolver,
+    /// default for the NCN's treasury
+    pub slash_destination: Pubkey,
+
+    /// The number of execution attempts that could not slash
+    execution_attempts: PodU64,
+
+    /// The slot of the last execution attempt that could not slash, 0 if there was none
+    last_attempt_slot: PodU64,
+
+    /// The layout version of the account, 0 for accounts created before versioning
+    version: u8,
     // Reserved space
     // reserved: [u8; 263],
 }
@@ -50,18 +126,43 @@ impl Default for SlashProposal {
             delete_deadline_slot: PodU64::from(0),
             completed: PodBool::from_bool(false),
             bump: 0,
+            extensions_count: PodU64::from(0),
+            veto_resolver: Pubkey::default(),
+            veto_reason: [0; 128],
+            slash_bps: PodU16::from(0),
+            batch_leader: Pubkey::default(),
+            approval_threshold: PodU64::from(0),
+            approvals_count: PodU64::from(0),
+            approvals: [Pubkey::default(); Self::MAX_APPROVALS],
+            execution_delay_slots: PodU64::from(0),
+            slash_destination: Pubkey::default(),
+            execution_attempts: PodU64::from(0),
+            last_attempt_slot: PodU64::from(0),
+            version: 0,
             // reserved: [0; 263],
         }
     }
 }
 
 impl SlashProposal {
+    /// The maximum number of approvals, one per approver role: the slasher admin, the slasher's
+    /// delegate admin and the NCN's resolver admin
+    pub const MAX_APPROVALS: usize = 3;
+
+    /// The layout version written by [`SlashProposal::new`] and by migration
+    pub const CURRENT_VERSION: u8 = 1;
+
+    /// The size of a slash proposal account created with the original, unversioned layout,
+    /// including the discriminator
+    pub const LEGACY_SIZE: usize = 106;
+
     pub fn new(
         operator: Pubkey,
         slasher: Pubkey,
         amount: u64,
         capture_slot: u64,
         veto_deadline_slot: u64,
+        slash_bps: u16,
         bump: u8,
     ) -> Self {
         Self {
@@ -73,6 +174,19 @@ impl SlashProposal {
             delete_deadline_slot: PodU64::from(capture_slot),
             completed: PodBool::from_bool(false),
             bump,
+            extensions_count: PodU64::from(0),
+            veto_resolver: Pubkey::default(),
+            veto_reason: [0; 128],
+            slash_bps: PodU16::from(slash_bps),
+            batch_leader: Pubkey::default(),
+            approval_threshold: PodU64::from(0),
+            approvals_count: PodU64::from(0),
+            approvals: [Pubkey::default(); Self::MAX_APPROVALS],
+            execution_delay_slots: PodU64::from(0),
+            slash_destination: Pubkey::default(),
+            execution_attempts: PodU64::from(0),
+            last_attempt_slot: PodU64::from(0),
+            version: Self::CURRENT_VERSION,
             // reserved: [0; 263],
         }
     }
@@ -81,6 +195,153 @@ impl SlashProposal {
         self.amount.into()
     }
 
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    pub fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    pub fn slash_bps(&self) -> u16 {
+        self.slash_bps.into()
+    }
+
+    pub fn approval_threshold(&self) -> u64 {
+        self.approval_threshold.into()
+    }
+
+    pub fn set_approval_threshold(&mut self, approval_threshold: u64) {
+        self.approval_threshold = PodU64::from(approval_threshold);
+    }
+
+    pub fn approvals_count(&self) -> u64 {
+        self.approvals_count.into()
+    }
+
+    /// Returns the signers that approved the slash proposal
+    pub fn approvals(&self) -> &[Pubkey] {
+        &self.approvals[..self.approvals_count() as usize]
+    }
+
+    /// Records `approver`'s approval, failing if it already approved
+    ///
+    /// # Arguments
+    /// * `approver` - The signer approving the slash proposal
+    pub fn approve(&mut self, approver: Pubkey) -> Result<(), ResolverError> {
+        if self.approvals().contains(&approver) {
+            msg!("{} already approved the slash proposal", approver);
+            return Err(ResolverError::SlashProposalAlreadyApproved);
+        }
+
+        let count = self.approvals_count() as usize;
+        if count >= Self::MAX_APPROVALS {
+            msg!("Slash proposal already has {} approvals", count);
+            return Err(ResolverError::SlashProposalAlreadyApproved);
+        }
+
+        self.approvals[count] = approver;
+        self.approvals_count = PodU64::from(count as u64 + 1);
+
+        Ok(())
+    }
+
+    pub fn check_approval_threshold_reached(&self) -> Result<(), ResolverError> {
+        if self.approvals_count() < self.approval_threshold() {
+            msg!(
+                "Slash proposal has {} of {} approvals",
+                self.approvals_count(),
+                self.approval_threshold()
+            );
+            return Err(ResolverError::ApprovalThresholdNotReached);
+        }
+
+        Ok(())
+    }
+
+    pub fn execution_delay_slots(&self) -> u64 {
+        self.execution_delay_slots.into()
+    }
+
+    pub fn set_execution_delay_slots(&mut self, execution_delay_slots: u64) {
+        self.execution_delay_slots = PodU64::from(execution_delay_slots);
+    }
+
+    /// The first slot the slash can be executed at, the veto deadline plus the execution delay
+    pub fn executable_after_slot(&self) -> Result<u64, ResolverError> {
+        self.veto_deadline_slot()
+            .checked_add(self.execution_delay_slots())
+            .ok_or(ResolverError::ArithmeticOverflow)
+    }
+
+    pub fn check_execution_delay_elapsed(&self, current_slot: u64) -> Result<(), ResolverError> {
+        let executable_after_slot = self.executable_after_slot()?;
+        if current_slot < executable_after_slot {
+            msg!(
+                "Execution delay not elapsed, executable after slot {}",
+                executable_after_slot
+            );
+            return Err(ResolverError::ExecutionDelayNotElapsed);
+        }
+
+        Ok(())
+    }
+
+    pub fn set_batch_leader(&mut self, batch_leader: Pubkey) {
+        self.batch_leader = batch_leader;
+    }
+
+    pub fn set_slash_destination(&mut self, slash_destination: Pubkey) {
+        self.slash_destination = slash_destination;
+    }
+
+    /// Splits a batch slash amount across operators proportionally to their weights.
+    /// Each share is rounded down and the remainder is added to the first operator.
+    ///
+    /// # Arguments
+    /// * `slash_amount` - The total amount to slash
+    /// * `weights` - The weight of each operator in the batch
+    ///
+    /// # Returns
+    /// * `Result<Vec<u64>, ResolverError>` - The amount to slash from each operator
+    pub fn split_batch_amount(
+        slash_amount: u64,
+        weights: &[u64],
+    ) -> Result<Vec<u64>, ResolverError> {
+        let total_weight = weights
+            .iter()
+            .try_fold(0_u128, |total, weight| total.checked_add(*weight as u128))
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+        if total_weight == 0 {
+            msg!("Slash batch total weight is zero");
+            return Err(ResolverError::SlashBatchInvalid);
+        }
+
+        let mut amounts = weights
+            .iter()
+            .map(|weight| {
+                (slash_amount as u128)
+                    .checked_mul(*weight as u128)
+                    .and_then(|product| product.checked_div(total_weight))
+                    .and_then(|quotient| u64::try_from(quotient).ok())
+                    .ok_or(ResolverError::ArithmeticOverflow)
+            })
+            .collect::<Result<Vec<u64>, ResolverError>>()?;
+
+        let distributed = amounts
+            .iter()
+            .try_fold(0_u64, |total, amount| total.checked_add(*amount))
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+        let remainder = slash_amount
+            .checked_sub(distributed)
+            .ok_or(ResolverError::ArithmeticUnderflow)?;
+        amounts[0] = amounts[0]
+            .checked_add(remainder)
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+
+        Ok(amounts)
+    }
+
     pub fn veto_deadline_slot(&self) -> u64 {
         self.veto_deadline_slot.into()
     }
@@ -93,14 +354,126 @@ impl SlashProposal {
         self.completed.into()
     }
 
+    pub fn extensions_count(&self) -> u64 {
+        self.extensions_count.into()
+    }
+
+    /// Pushes the veto deadline forward, failing once it has been extended `max_extensions` times
+    ///
+    /// # Arguments
+    /// * `extension_slots` - The number of slots to add to the veto deadline
+    /// * `max_extensions` - The maximum number of extensions allowed
+    pub fn extend_veto_deadline(
+        &mut self,
+        extension_slots: u64,
+        max_extensions: u64,
+    ) -> Result<(), ResolverError> {
+        if self.extensions_count() >= max_extensions {
+            msg!(
+                "Veto deadline has already been extended {} times",
+                max_extensions
+            );
+            return Err(ResolverError::SlashProposalMaxVetoExtensionsReached);
+        }
+
+        let veto_deadline_slot = self
+            .veto_deadline_slot()
+            .checked_add(extension_slots)
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+        let extensions_count = self
+            .extensions_count()
+            .checked_add(1)
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+
+        self.veto_deadline_slot = PodU64::from(veto_deadline_slot);
+        self.extensions_count = PodU64::from(extensions_count);
+
+        Ok(())
+    }
+
+    pub fn execution_attempts(&self) -> u64 {
+        self.execution_attempts.into()
+    }
+
+    pub fn last_attempt_slot(&self) -> u64 {
+        self.last_attempt_slot.into()
+    }
+
+    /// Records an execution attempt that could not slash and reopens the veto window until
+    /// `current_slot + re_veto_window_slots`, so resolvers can re-examine the proposal before
+    /// it is attempted again
+    ///
+    /// # Arguments
+    /// * `current_slot` - The slot of the failed attempt
+    /// * `re_veto_window_slots` - The number of slots the veto window is reopened for
+    pub fn record_failed_execution(
+        &mut self,
+        current_slot: u64,
+        re_veto_window_slots: u64,
+    ) -> Result<(), ResolverError> {
+        let execution_attempts = self
+            .execution_attempts()
+            .checked_add(1)
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+        let veto_deadline_slot = current_slot
+            .checked_add(re_veto_window_slots)
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+
+        self.execution_attempts = PodU64::from(execution_attempts);
+        self.last_attempt_slot = PodU64::from(current_slot);
+        self.veto_deadline_slot = PodU64::from(veto_deadline_slot);
+
+        Ok(())
+    }
+
+    pub fn veto_reason(&self) -> &[u8; 128] {
+        &self.veto_reason
+    }
+
+    /// Records the resolver that vetoed the slash proposal and its reason
+    pub fn set_veto(&mut self, veto_resolver: Pubkey, veto_reason: [u8; 128]) {
+        self.veto_resolver = veto_resolver;
+        self.veto_reason = veto_reason;
+    }
+
     pub fn set_delete_deadline_slot(&mut self, deadline_slot: u64) {
-        self.veto_deadline_slot = PodU64::from(deadline_slot);
+        self.delete_deadline_slot = PodU64::from(deadline_slot);
+    }
+
+    /// Derives the [`SlashProposalState`] of the slash proposal at `current_slot`
+    ///
+    /// # Arguments
+    /// * `current_slot` - The current slot
+    pub fn state(&self, current_slot: u64) -> SlashProposalState {
+        if self.completed() {
+            if current_slot >= self.delete_deadline_slot() {
+                return SlashProposalState::Expired;
+            }
+            if self.veto_resolver.ne(&Pubkey::default()) {
+                return SlashProposalState::Vetoed;
+            }
+            return SlashProposalState::Completed;
+        }
+
+        if current_slot < self.veto_deadline_slot() {
+            return SlashProposalState::InVetoWindow;
+        }
+
+        if self.check_execution_delay_elapsed(current_slot).is_ok()
+            && self.check_approval_threshold_reached().is_ok()
+        {
+            return SlashProposalState::Executable;
+        }
+
+        SlashProposalState::AwaitingExecution
     }
 
     pub fn set_completed(&mut self, completed: bool) {
         self.completed = PodBool::from_bool(completed);
     }
 
+    /// Errors once the veto window has closed. The window is `[capture_slot, veto_deadline_slot)`,
+    /// so a proposal can no longer be vetoed at `veto_deadline_slot` itself.
     pub fn check_veto_period_ended(&self, current_slot: u64) -> Result<(), ResolverError> {
         if self.veto_deadline_slot() <= current_slot {
             msg!("Veto period ended");
@@ -110,6 +483,9 @@ impl SlashProposal {
         Ok(())
     }
 
+    /// Errors while the veto window is still open. The complement of
+    /// [`Self::check_veto_period_ended`]: a proposal first becomes executable at
+    /// `veto_deadline_slot`, so no slot is both vetoable and executable.
     pub fn check_veto_period_not_ended(&self, current_slot: u64) -> Result<(), ResolverError> {
         if self.veto_deadline_slot() > current_slot {
             msg!("Veto period not ended");
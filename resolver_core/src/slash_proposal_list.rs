@@ -0,0 +1,251 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use resolver_sdk::error::ResolverError;
+use shank::{ShankAccount, ShankType};
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+/// The status of a slash proposal tracked in a [`SlashProposalList`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SlashProposalStatus {
+    Proposed = 1,
+    Vetoed = 2,
+    Executed = 3,
+}
+
+impl TryFrom<u8> for SlashProposalStatus {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Proposed),
+            2 => Ok(Self::Vetoed),
+            3 => Ok(Self::Executed),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// A single slash proposal recorded in a [`SlashProposalList`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, ShankType)]
+#[repr(C)]
+pub struct SlashProposalEntry {
+    /// The slash proposal account
+    pub slash_proposal: Pubkey,
+
+    /// The [`SlashProposalStatus`] of the slash proposal
+    status: u8,
+}
+
+impl SlashProposalEntry {
+    pub fn status(&self) -> Result<SlashProposalStatus, ProgramError> {
+        SlashProposalStatus::try_from(self.status)
+    }
+}
+
+/// Tracks the most recent slash proposals against an operator in a NCN so they
+/// can be found without scanning every slash proposal account.
+///
+/// Entries are stored in a fixed-size ring buffer, once it is full the oldest
+/// entry is overwritten.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct SlashProposalList {
+    /// The NCN account
+    pub ncn: Pubkey,
+
+    /// The operator account
+    pub operator: Pubkey,
+
+    /// The index the next entry is written to
+    head: PodU64,
+
+    /// The total number of slash proposals pushed to the list
+    count: PodU64,
+
+    /// The ring buffer of slash proposals
+    entries: [SlashProposalEntry; 32],
+
+    /// The total amount of slash proposals against the operator that are neither vetoed,
+    /// executed nor deleted
+    outstanding_slash_amount: PodU64,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+}
+
+impl Discriminator for SlashProposalList {
+    const DISCRIMINATOR: u8 = 7;
+}
+
+impl SlashProposalList {
+    pub const MAX_ENTRIES: usize = 32;
+
+    pub fn new(ncn: Pubkey, operator: Pubkey, bump: u8) -> Self {
+        Self {
+            ncn,
+            operator,
+            head: PodU64::from(0),
+            count: PodU64::from(0),
+            entries: [SlashProposalEntry::zeroed(); Self::MAX_ENTRIES],
+            outstanding_slash_amount: PodU64::from(0),
+            bump,
+        }
+    }
+
+    pub fn head(&self) -> u64 {
+        self.head.into()
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count.into()
+    }
+
+    /// Returns the populated entries of the ring buffer, in storage order
+    pub fn entries(&self) -> &[SlashProposalEntry] {
+        let len = (self.count() as usize).min(Self::MAX_ENTRIES);
+        &self.entries[..len]
+    }
+
+    /// Returns the entry for `slash_proposal`, if it is still tracked
+    pub fn get(&self, slash_proposal: &Pubkey) -> Option<&SlashProposalEntry> {
+        self.entries()
+            .iter()
+            .find(|entry| entry.slash_proposal.eq(slash_proposal))
+    }
+
+    /// Pushes a new entry, overwriting the oldest entry once the list is full
+    ///
+    /// # Arguments
+    /// * `slash_proposal` - The slash proposal account
+    /// * `status` - The status of the slash proposal
+    pub fn push(&mut self, slash_proposal: Pubkey, status: SlashProposalStatus) {
+        let head = self.head() as usize % Self::MAX_ENTRIES;
+        self.entries[head] = SlashProposalEntry {
+            slash_proposal,
+            status: status as u8,
+        };
+        self.head = PodU64::from(((head + 1) % Self::MAX_ENTRIES) as u64);
+        self.count = PodU64::from(self.count().saturating_add(1));
+    }
+
+    /// Updates the status of `slash_proposal`, pushing a new entry if it has
+    /// already been evicted from the list
+    ///
+    /// # Arguments
+    /// * `slash_proposal` - The slash proposal account
+    /// * `status` - The new status of the slash proposal
+    pub fn update_status(&mut self, slash_proposal: Pubkey, status: SlashProposalStatus) {
+        let len = (self.count() as usize).min(Self::MAX_ENTRIES);
+        match self.entries[..len]
+            .iter_mut()
+            .find(|entry| entry.slash_proposal.eq(&slash_proposal))
+        {
+            Some(entry) => entry.status = status as u8,
+            None => self.push(slash_proposal, status),
+        }
+    }
+
+    pub fn outstanding_slash_amount(&self) -> u64 {
+        self.outstanding_slash_amount.into()
+    }
+
+    /// Adds a newly proposed slash to the outstanding amount, failing if the outstanding
+    /// amount would exceed the operator's stake
+    ///
+    /// # Arguments
+    /// * `amount` - The amount of the new slash proposal
+    /// * `stake` - The operator's delegated stake
+    pub fn add_outstanding_slash_amount(
+        &mut self,
+        amount: u64,
+        stake: u64,
+    ) -> Result<(), ResolverError> {
+        let outstanding_slash_amount = self
+            .outstanding_slash_amount()
+            .checked_add(amount)
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+        if outstanding_slash_amount > stake {
+            msg!(
+                "Outstanding slash amount {} exceeds operator stake {}",
+                outstanding_slash_amount,
+                stake
+            );
+            return Err(ResolverError::OutstandingSlashExceedsStake);
+        }
+        self.outstanding_slash_amount = PodU64::from(outstanding_slash_amount);
+
+        Ok(())
+    }
+
+    /// Removes a vetoed, executed or deleted slash from the outstanding amount
+    ///
+    /// # Arguments
+    /// * `amount` - The amount of the slash proposal
+    pub fn release_outstanding_slash_amount(&mut self, amount: u64) {
+        self.outstanding_slash_amount =
+            PodU64::from(self.outstanding_slash_amount().saturating_sub(amount));
+    }
+
+    pub fn seeds(ncn: &Pubkey, operator: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"slash_proposal_list".to_vec(),
+            ncn.as_ref().to_vec(),
+            operator.as_ref().to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(ncn, operator);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the account as an [`SlashProposalList`] account, returning an error if it is not.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `slash_proposal_list` - The account to load the SlashProposalList from
+    /// * `ncn` - The NCN account
+    /// * `operator` - The operator account
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        slash_proposal_list: &AccountInfo,
+        ncn: &AccountInfo,
+        operator: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if slash_proposal_list.owner.ne(program_id) {
+            msg!("SlashProposalList account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if slash_proposal_list.data_is_empty() {
+            msg!("SlashProposalList account data is empty");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if expect_writable && !slash_proposal_list.is_writable {
+            msg!("SlashProposalList account is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if slash_proposal_list.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("SlashProposalList account discriminator is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let expected_pubkey = Self::find_program_address(program_id, ncn.key, operator.key).0;
+        if slash_proposal_list.key.ne(&expected_pubkey) {
+            msg!("SlashProposalList account is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+}
@@ -16,6 +16,12 @@ pub struct Slasher {
     /// The delegate admin can delegate assets from the slasher
     pub delegate_admin: Pubkey,
 
+    /// The propose admin can propose slashes
+    pub propose_admin: Pubkey,
+
+    /// The execute admin can execute slash proposals
+    pub execute_admin: Pubkey,
+
     /// The slasher index
     index: PodU64,
 
@@ -33,6 +39,8 @@ impl Slasher {
             base,
             admin,
             delegate_admin: admin,
+            propose_admin: admin,
+            execute_admin: admin,
             index: PodU64::from(index),
             bump,
         }
@@ -52,6 +60,14 @@ impl Slasher {
             self.delegate_admin = *new_admin;
             msg!("Delegate admin set to {:?}", new_admin);
         }
+        if self.propose_admin.eq(old_admin) {
+            self.propose_admin = *new_admin;
+            msg!("Propose admin set to {:?}", new_admin);
+        }
+        if self.execute_admin.eq(old_admin) {
+            self.execute_admin = *new_admin;
+            msg!("Execute admin set to {:?}", new_admin);
+        }
     }
 
     pub fn check_admin(&self, candidate_slasher_admin: &Pubkey) -> Result<(), ResolverError> {
@@ -87,6 +103,30 @@ impl Slasher {
         Ok(())
     }
 
+    pub fn check_propose_admin(&self, propose_admin: &Pubkey) -> Result<(), ResolverError> {
+        if self.propose_admin.ne(propose_admin) {
+            msg!(
+                "Incorrect propose_admin provided, expected {}, received {}",
+                self.propose_admin,
+                propose_admin
+            );
+            return Err(ResolverError::SlasherProposeAdminInvalid);
+        }
+        Ok(())
+    }
+
+    pub fn check_execute_admin(&self, execute_admin: &Pubkey) -> Result<(), ResolverError> {
+        if self.execute_admin.ne(execute_admin) {
+            msg!(
+                "Incorrect execute_admin provided, expected {}, received {}",
+                self.execute_admin,
+                execute_admin
+            );
+            return Err(ResolverError::SlasherExecuteAdminInvalid);
+        }
+        Ok(())
+    }
+
     pub fn seeds(base: &Pubkey) -> Vec<Vec<u8>> {
         Vec::from_iter([b"slasher".to_vec(), base.as_ref().to_vec()])
     }
@@ -0,0 +1,77 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use resolver_core::{
+    config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
+    slash_proposal::SlashProposal, slasher::Slasher,
+};
+use resolver_sdk::error::ResolverError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Records an approval of a slash proposal. The approver must be the slasher admin, the slasher's
+/// delegate admin or the NCN's resolver admin, and each can approve once.
+pub fn process_approve_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config_info, ncn_resolver_program_config_info, ncn_info, operator_info, slasher_info, slash_proposal_info, approver] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    NcnResolverProgramConfig::load(
+        program_id,
+        ncn_resolver_program_config_info,
+        ncn_info,
+        false,
+    )?;
+    let ncn_resolver_program_config_data = ncn_resolver_program_config_info.data.borrow();
+    let ncn_resolver_program_config =
+        NcnResolverProgramConfig::try_from_slice_unchecked(&ncn_resolver_program_config_data)?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+    Operator::load(&config.jito_restaking_program, operator_info, false)?;
+
+    Slasher::load(program_id, slasher_info, false)?;
+    let slasher_data = slasher_info.data.borrow();
+    let slasher = Slasher::try_from_slice_unchecked(&slasher_data)?;
+
+    SlashProposal::load(
+        program_id,
+        slash_proposal_info,
+        ncn_info,
+        operator_info,
+        slasher_info,
+        true,
+    )?;
+    let mut slash_proposal_data = slash_proposal_info.data.borrow_mut();
+    let slash_proposal = SlashProposal::try_from_slice_unchecked_mut(&mut slash_proposal_data)?;
+
+    load_signer(approver, false)?;
+
+    if slasher.admin.ne(approver.key)
+        && slasher.delegate_admin.ne(approver.key)
+        && ncn_resolver_program_config.resolver_admin.ne(approver.key)
+    {
+        msg!("{} is not an approver of the slash proposal", approver.key);
+        return Err(ResolverError::SlashApproverInvalid.into());
+    }
+
+    slash_proposal.check_completed()?;
+    slash_proposal.approve(*approver.key)?;
+
+    msg!(
+        "Slash proposal {} approved by {} ({} of {})",
+        slash_proposal_info.key,
+        approver.key,
+        slash_proposal.approvals_count(),
+        slash_proposal.approval_threshold()
+    );
+
+    Ok(())
+}
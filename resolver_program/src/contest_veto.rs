@@ -0,0 +1,69 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use resolver_core::{
+    config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
+    slash_proposal::SlashProposal, slasher::Slasher,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Contests a slash proposal within its veto window, pushing the veto deadline forward by the
+/// NCN's `veto_extension_slots` up to `max_veto_extensions` times.
+pub fn process_contest_veto(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config_info, ncn_resolver_program_config_info, ncn_info, operator_info, slasher_info, slash_proposal_info, ncn_resolver_admin] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    NcnResolverProgramConfig::load(
+        program_id,
+        ncn_resolver_program_config_info,
+        ncn_info,
+        false,
+    )?;
+    let ncn_resolver_program_config_data = ncn_resolver_program_config_info.data.borrow();
+    let ncn_resolver_program_config =
+        NcnResolverProgramConfig::try_from_slice_unchecked(&ncn_resolver_program_config_data)?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+    Operator::load(&config.jito_restaking_program, operator_info, false)?;
+    Slasher::load(program_id, slasher_info, false)?;
+
+    SlashProposal::load(
+        program_id,
+        slash_proposal_info,
+        ncn_info,
+        operator_info,
+        slasher_info,
+        true,
+    )?;
+    let mut slash_proposal_data = slash_proposal_info.data.borrow_mut();
+    let slash_proposal = SlashProposal::try_from_slice_unchecked_mut(&mut slash_proposal_data)?;
+
+    load_signer(ncn_resolver_admin, false)?;
+
+    ncn_resolver_program_config.check_resolver_admin(ncn_resolver_admin.key)?;
+
+    slash_proposal.check_veto_period_ended(Clock::get()?.slot)?;
+    slash_proposal.check_completed()?;
+
+    slash_proposal.extend_veto_deadline(
+        ncn_resolver_program_config.veto_extension_slots(),
+        ncn_resolver_program_config.max_veto_extensions(),
+    )?;
+
+    msg!(
+        "Veto deadline extended to slot {}",
+        slash_proposal.veto_deadline_slot()
+    );
+
+    Ok(())
+}
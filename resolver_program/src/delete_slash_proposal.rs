@@ -6,7 +6,7 @@ use jito_jsm_core::{
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use resolver_core::{
     config::Config, ncn_slash_proposal_ticket::NcnSlashProposalTicket,
-    slash_proposal::SlashProposal, slasher::Slasher,
+    slash_proposal::SlashProposal, slash_proposal_list::SlashProposalList, slasher::Slasher,
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
@@ -17,7 +17,7 @@ pub fn process_delete_slash_proposal(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config_info, ncn_info, operator_info, slasher_info, slash_proposal_info, ncn_slash_proposal_ticket_info, payer, system_program] =
+    let [config_info, ncn_info, operator_info, slasher_info, slash_proposal_info, ncn_slash_proposal_ticket_info, slash_proposal_list_info, payer, system_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -51,6 +51,17 @@ pub fn process_delete_slash_proposal(
         true,
     )?;
 
+    SlashProposalList::load(
+        program_id,
+        slash_proposal_list_info,
+        ncn_info,
+        operator_info,
+        true,
+    )?;
+    let mut slash_proposal_list_data = slash_proposal_list_info.data.borrow_mut();
+    let slash_proposal_list =
+        SlashProposalList::try_from_slice_unchecked_mut(&mut slash_proposal_list_data)?;
+
     load_signer(payer, true)?;
     load_system_program(system_program)?;
 
@@ -58,6 +69,11 @@ pub fn process_delete_slash_proposal(
 
     slash_proposal.check_delete_deadline_ended(current_slot)?;
 
+    // Vetoed and executed proposals were already released
+    if !slash_proposal.completed() {
+        slash_proposal_list.release_outstanding_slash_amount(slash_proposal.amount());
+    }
+
     drop(slash_proposal_data);
 
     close_program_account(program_id, slash_proposal_info, payer)?;
@@ -1,3 +1,4 @@
+use borsh::BorshSerialize;
 use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::{load_associated_token_account, load_signer, load_token_program};
 use jito_restaking_core::{
@@ -13,16 +14,22 @@ use jito_vault_core::{
 use jito_vault_sdk::error::VaultError;
 use resolver_core::{
     ncn_resolver_program_config::NcnResolverProgramConfig,
-    ncn_slash_proposal_ticket::NcnSlashProposalTicket, resolver::Resolver,
-    slash_proposal::SlashProposal, slasher::Slasher,
+    ncn_slash_proposal_ticket::NcnSlashProposalTicket,
+    ncn_slash_stats::NcnSlashStats,
+    resolver::Resolver,
+    slash_proposal::SlashProposal,
+    slash_proposal_list::{SlashProposalList, SlashProposalStatus},
+    slasher::Slasher,
 };
+use resolver_sdk::{error::ResolverError, event::SlashExecuted};
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program::invoke_signed, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, log::sol_log_data, msg,
+    program::invoke_signed, program_error::ProgramError, program_pack::Pack, pubkey::Pubkey,
+    sysvar::Sysvar,
 };
 
 pub fn process_execute_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let [config_info, ncn_resolver_program_config_info, vault_config_info, ncn_info, operator_info, slasher_info, vault_info, slasher_admin_info, ncn_operator_state_info, ncn_vault_ticket_info, operator_vault_ticket_info, vault_ncn_ticket_info, vault_operator_delegation_info, ncn_vault_slasher_ticket_info, vault_ncn_slasher_ticket_info, vault_ncn_slasher_operator_ticket_info, vault_token_account_info, slasher_token_account_info, resolver_info, slash_proposal_info, ncn_slash_proposal_ticket_info, token_program, jito_vault_program] =
+    let [config_info, ncn_resolver_program_config_info, vault_config_info, ncn_info, operator_info, slasher_info, vault_info, slasher_admin_info, ncn_operator_state_info, ncn_vault_ticket_info, operator_vault_ticket_info, vault_ncn_ticket_info, vault_operator_delegation_info, ncn_vault_slasher_ticket_info, vault_ncn_slasher_ticket_info, vault_ncn_slasher_operator_ticket_info, vault_token_account_info, slasher_token_account_info, resolver_token_account_info, slash_destination_token_account_info, resolver_info, slash_proposal_info, ncn_slash_proposal_ticket_info, slash_proposal_list_info, ncn_slash_stats_info, token_program, jito_vault_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -132,10 +139,9 @@ pub fn process_execute_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
         slasher_info.key,
         &vault.supported_mint,
     )?;
-
     Resolver::load(program_id, resolver_info, false)?;
     let resolver_data = resolver_info.data.borrow();
-    let _resolver = Resolver::try_from_slice_unchecked(&resolver_data)?;
+    let resolver = Resolver::try_from_slice_unchecked(&resolver_data)?;
 
     SlashProposal::load(
         program_id,
@@ -156,9 +162,47 @@ pub fn process_execute_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
         false,
     )?;
     let ncn_slash_proposal_ticket_data = ncn_slash_proposal_ticket_info.data.borrow();
-    let _ncn_slash_proposal_ticket =
+    let ncn_slash_proposal_ticket =
         NcnSlashProposalTicket::try_from_slice_unchecked(&ncn_slash_proposal_ticket_data)?;
 
+    // Without a resolver assigned to the proposal, the slash destination receives the full amount
+    let has_resolver = ncn_slash_proposal_ticket.resolver.ne(&Pubkey::default());
+    if has_resolver {
+        if ncn_slash_proposal_ticket.resolver.ne(resolver_info.key) {
+            msg!("Resolver is not assigned to the slash proposal");
+            return Err(ResolverError::SlashProposalResolverInvalid.into());
+        }
+        load_associated_token_account(
+            resolver_token_account_info,
+            &resolver.admin,
+            &vault.supported_mint,
+        )?;
+    }
+
+    // Checked at execution so a destination removed from the allowlist can't be paid out to
+    let slash_destination =
+        ncn_resolver_program_config.slash_destination_owner(&slash_proposal.slash_destination)?;
+    load_associated_token_account(
+        slash_destination_token_account_info,
+        &slash_destination,
+        &vault.supported_mint,
+    )?;
+
+    SlashProposalList::load(
+        program_id,
+        slash_proposal_list_info,
+        ncn_info,
+        operator_info,
+        true,
+    )?;
+    let mut slash_proposal_list_data = slash_proposal_list_info.data.borrow_mut();
+    let slash_proposal_list =
+        SlashProposalList::try_from_slice_unchecked_mut(&mut slash_proposal_list_data)?;
+
+    NcnSlashStats::load(program_id, ncn_slash_stats_info, ncn_info, true)?;
+    let mut ncn_slash_stats_data = ncn_slash_stats_info.data.borrow_mut();
+    let ncn_slash_stats = NcnSlashStats::try_from_slice_unchecked_mut(&mut ncn_slash_stats_data)?;
+
     load_token_program(token_program)?;
 
     if jito_vault_program.key.ne(&jito_vault_program::id()) {
@@ -166,10 +210,36 @@ pub fn process_execute_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
         return Err(ProgramError::InvalidAccountData);
     }
 
-    slasher.check_admin(slasher_admin_info.key)?;
+    slasher.check_execute_admin(slasher_admin_info.key)?;
 
-    slash_proposal.check_veto_period_not_ended(Clock::get()?.slot)?;
+    // Executable from veto_deadline_slot onwards, the first slot it can no longer be vetoed
+    let current_slot = Clock::get()?.slot;
+    slash_proposal.check_veto_period_not_ended(current_slot)?;
+    slash_proposal.check_execution_delay_elapsed(current_slot)?;
     slash_proposal.check_completed()?;
+    slash_proposal.check_approval_threshold_reached()?;
+
+    // A failed transaction would revert the attempt, so a slash the operator's delegation can't
+    // cover is recorded instead and the veto window reopened for resolvers to re-examine it
+    let delegated_stake = {
+        let vault_operator_delegation_data = vault_operator_delegation_info.data.borrow();
+        VaultOperatorDelegation::try_from_slice_unchecked(&vault_operator_delegation_data)?
+            .delegation_state
+            .total_security()?
+    };
+    if delegated_stake < slash_proposal.amount() {
+        slash_proposal.record_failed_execution(
+            current_slot,
+            ncn_resolver_program_config.re_veto_window_slots(),
+        )?;
+        msg!(
+            "Operator delegation {} can't cover slash of {}, veto window reopened until slot {}",
+            delegated_stake,
+            slash_proposal.amount(),
+            slash_proposal.veto_deadline_slot()
+        );
+        return Ok(());
+    }
 
     slash_proposal.set_completed(true);
     slash_proposal.set_delete_deadline_slot(
@@ -177,6 +247,18 @@ pub fn process_execute_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
             + ncn_resolver_program_config.delete_slash_proposal_duration(),
     );
 
+    slash_proposal_list.update_status(*slash_proposal_info.key, SlashProposalStatus::Executed);
+    slash_proposal_list.release_outstanding_slash_amount(slash_proposal.amount());
+    ncn_slash_stats.record_execution(slash_proposal.amount());
+
+    let (resolver_amount, destination_amount) = if has_resolver {
+        ncn_resolver_program_config.split_slash_amount(slash_proposal.amount())?
+    } else {
+        (0, slash_proposal.amount())
+    };
+
+    let vault_balance_before = token_account_amount(vault_token_account_info)?;
+
     let slasher_seeds = slasher.signing_seeds();
     let seed_slices: Vec<&[u8]> = slasher_seeds.iter().map(|seed| seed.as_slice()).collect();
 
@@ -226,5 +308,48 @@ pub fn process_execute_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
         &[&seed_slices],
     )?;
 
+    let slash_executed = SlashExecuted::new(
+        *slash_proposal_info.key,
+        *operator_info.key,
+        slash_proposal.amount(),
+        vault_balance_before,
+        token_account_amount(vault_token_account_info)?,
+        current_slot,
+    )?;
+    sol_log_data(&[&slash_executed.try_to_vec()?]);
+
+    for (destination_info, amount) in [
+        (resolver_token_account_info, resolver_amount),
+        (slash_destination_token_account_info, destination_amount),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+
+        msg!("Transferring {} to {}", amount, destination_info.key);
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program.key,
+                slasher_token_account_info.key,
+                destination_info.key,
+                slasher_info.key,
+                &[],
+                amount,
+            )?,
+            &[
+                slasher_token_account_info.clone(),
+                destination_info.clone(),
+                slasher_info.clone(),
+                token_program.clone(),
+            ],
+            &[&seed_slices],
+        )?;
+    }
+
     Ok(())
 }
+
+/// Reads the token balance of an SPL token account
+fn token_account_amount(token_account_info: &AccountInfo) -> Result<u64, ProgramError> {
+    Ok(spl_token::state::Account::unpack(&token_account_info.data.borrow())?.amount)
+}
@@ -0,0 +1,57 @@
+use borsh::BorshSerialize;
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::ncn::Ncn;
+use resolver_core::{config::Config, ncn_resolver_program_config::NcnResolverProgramConfig};
+use resolver_sdk::return_data::ConfigStatus;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::set_return_data,
+    program_error::ProgramError, pubkey::Pubkey,
+};
+
+/// Returns a snapshot of the NCN's resolver program configuration as return data so callers can
+/// read it from a simulated transaction without decoding the account layout
+pub fn process_get_config_status(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config_info, ncn_resolver_program_config_info, ncn_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+
+    NcnResolverProgramConfig::load(
+        program_id,
+        ncn_resolver_program_config_info,
+        ncn_info,
+        false,
+    )?;
+    let ncn_resolver_program_config_data = ncn_resolver_program_config_info.data.borrow();
+    let ncn_resolver_program_config =
+        NcnResolverProgramConfig::try_from_slice_unchecked(&ncn_resolver_program_config_data)?;
+
+    let status = ConfigStatus {
+        epoch_length: config.epoch_length(),
+        resolver_admin: ncn_resolver_program_config.resolver_admin,
+        veto_duration: ncn_resolver_program_config.veto_duration(),
+        delete_slash_proposal_duration: ncn_resolver_program_config
+            .delete_slash_proposal_duration(),
+        resolver_count: ncn_resolver_program_config.resolver_count(),
+        resolver_change_delay: ncn_resolver_program_config.resolver_change_delay(),
+        treasury: ncn_resolver_program_config.treasury,
+        resolver_share_bps: ncn_resolver_program_config.resolver_share_bps(),
+        veto_extension_slots: ncn_resolver_program_config.veto_extension_slots(),
+        max_veto_extensions: ncn_resolver_program_config.max_veto_extensions(),
+        blacklist_len: ncn_resolver_program_config.blacklist_len(),
+        approval_threshold: ncn_resolver_program_config.approval_threshold(),
+        execution_delay_slots: ncn_resolver_program_config.execution_delay_slots(),
+        re_veto_window_slots: ncn_resolver_program_config.re_veto_window_slots(),
+        slash_destinations_len: ncn_resolver_program_config.slash_destinations_len(),
+    };
+    msg!("{:?}", status);
+
+    set_return_data(&status.try_to_vec()?);
+
+    Ok(())
+}
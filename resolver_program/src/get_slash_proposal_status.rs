@@ -0,0 +1,60 @@
+use borsh::BorshSerialize;
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use resolver_core::{config::Config, slash_proposal::SlashProposal, slasher::Slasher};
+use resolver_sdk::return_data::SlashProposalStatusData;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::set_return_data, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Returns a snapshot of a slash proposal, including its state at the current slot, as return
+/// data
+pub fn process_get_slash_proposal_status(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config_info, ncn_info, operator_info, slasher_info, slash_proposal_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+    Operator::load(&config.jito_restaking_program, operator_info, false)?;
+    Slasher::load(program_id, slasher_info, false)?;
+
+    SlashProposal::load(
+        program_id,
+        slash_proposal_info,
+        ncn_info,
+        operator_info,
+        slasher_info,
+        false,
+    )?;
+    let slash_proposal_data = slash_proposal_info.data.borrow();
+    let slash_proposal = SlashProposal::try_from_slice_unchecked(&slash_proposal_data)?;
+
+    let current_slot = Clock::get()?.slot;
+    let state = slash_proposal.state(current_slot);
+    msg!("Slash proposal {} is {:?}", slash_proposal_info.key, state);
+
+    let status = SlashProposalStatusData {
+        operator: slash_proposal.operator,
+        slasher: slash_proposal.slasher,
+        amount: slash_proposal.amount(),
+        capture_slot: slash_proposal.capture_slot.into(),
+        veto_deadline_slot: slash_proposal.veto_deadline_slot(),
+        executable_after_slot: slash_proposal.executable_after_slot()?,
+        delete_deadline_slot: slash_proposal.delete_deadline_slot(),
+        approvals_count: slash_proposal.approvals_count(),
+        approval_threshold: slash_proposal.approval_threshold(),
+        state: state as u8,
+    };
+
+    set_return_data(&status.try_to_vec()?);
+
+    Ok(())
+}
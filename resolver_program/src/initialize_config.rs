@@ -16,6 +16,14 @@ pub fn process_initialize_config(program_id: &Pubkey, accounts: &[AccountInfo])
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
+    if config.owner.eq(program_id)
+        && !config.data_is_empty()
+        && config.data.borrow()[0].eq(&Config::DISCRIMINATOR)
+    {
+        msg!("Config account is already initialized");
+        return Err(ResolverError::ConfigAlreadyInitialized.into());
+    }
+
     load_system_account(config, true)?;
     load_signer(admin, true)?;
     load_system_program(system_program)?;
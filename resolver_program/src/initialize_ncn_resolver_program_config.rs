@@ -26,6 +26,14 @@ pub fn process_initialize_resolver_program_config(
     let config = Config::try_from_slice_unchecked(&config_data)?;
 
     Ncn::load(&config.jito_restaking_program, ncn, false)?;
+    if ncn_resolver_program_config.owner.eq(program_id)
+        && !ncn_resolver_program_config.data_is_empty()
+        && ncn_resolver_program_config.data.borrow()[0].eq(&NcnResolverProgramConfig::DISCRIMINATOR)
+    {
+        msg!("NcnResolverProgramConfig account is already initialized");
+        return Err(ResolverError::NcnResolverProgramConfigAlreadyInitialized.into());
+    }
+
     load_system_account(ncn_resolver_program_config, true)?;
     load_signer(admin, true)?;
     load_system_program(system_program)?;
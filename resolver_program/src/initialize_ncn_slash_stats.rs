@@ -0,0 +1,61 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program},
+};
+use jito_restaking_core::ncn::Ncn;
+use resolver_core::{config::Config, ncn_slash_stats::NcnSlashStats};
+use resolver_sdk::error::ResolverError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+pub fn process_initialize_ncn_slash_stats(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config_info, ncn_info, ncn_slash_stats_info, payer, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+    load_system_account(ncn_slash_stats_info, true)?;
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    let (ncn_slash_stats_pubkey, ncn_slash_stats_bump, mut ncn_slash_stats_seeds) =
+        NcnSlashStats::find_program_address(program_id, ncn_info.key);
+    ncn_slash_stats_seeds.push(vec![ncn_slash_stats_bump]);
+    if ncn_slash_stats_info.key.ne(&ncn_slash_stats_pubkey) {
+        msg!("NcnSlashStats account is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    msg!(
+        "Initializing ncn slash stats at address {}",
+        ncn_slash_stats_info.key
+    );
+    create_account(
+        payer,
+        ncn_slash_stats_info,
+        system_program,
+        program_id,
+        &Rent::get()?,
+        8_u64
+            .checked_add(std::mem::size_of::<NcnSlashStats>() as u64)
+            .ok_or(ResolverError::ArithmeticOverflow)?,
+        &ncn_slash_stats_seeds,
+    )?;
+
+    let mut ncn_slash_stats_data = ncn_slash_stats_info.try_borrow_mut_data()?;
+    ncn_slash_stats_data[0] = NcnSlashStats::DISCRIMINATOR;
+    let ncn_slash_stats = NcnSlashStats::try_from_slice_unchecked_mut(&mut ncn_slash_stats_data)?;
+    *ncn_slash_stats = NcnSlashStats::new(*ncn_info.key, ncn_slash_stats_bump);
+
+    Ok(())
+}
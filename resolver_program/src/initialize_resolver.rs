@@ -31,6 +31,14 @@ pub fn process_initialize_resolver(program_id: &Pubkey, accounts: &[AccountInfo]
     )?;
 
     Ncn::load(&config.jito_restaking_program, ncn, false)?;
+    if resolver_info.owner.eq(program_id)
+        && !resolver_info.data_is_empty()
+        && resolver_info.data.borrow()[0].eq(&Resolver::DISCRIMINATOR)
+    {
+        msg!("Resolver account is already initialized");
+        return Err(ResolverError::ResolverAlreadyInitialized.into());
+    }
+
     load_system_account(resolver_info, true)?;
     load_signer(admin, true)?;
     load_signer(base, false)?;
@@ -63,6 +71,7 @@ pub fn process_initialize_resolver(program_id: &Pubkey, accounts: &[AccountInfo]
 
     *resolver = Resolver::new(
         *base.key,
+        *ncn.key,
         *admin.key,
         ncn_resolver_program_config.resolver_count(),
         resolver_bump,
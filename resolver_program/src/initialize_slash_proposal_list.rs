@@ -0,0 +1,66 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program},
+};
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use resolver_core::{config::Config, slash_proposal_list::SlashProposalList};
+use resolver_sdk::error::ResolverError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+pub fn process_initialize_slash_proposal_list(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config_info, ncn_info, operator_info, slash_proposal_list_info, payer, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+    Operator::load(&config.jito_restaking_program, operator_info, false)?;
+    load_system_account(slash_proposal_list_info, true)?;
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    let (slash_proposal_list_pubkey, slash_proposal_list_bump, mut slash_proposal_list_seeds) =
+        SlashProposalList::find_program_address(program_id, ncn_info.key, operator_info.key);
+    slash_proposal_list_seeds.push(vec![slash_proposal_list_bump]);
+    if slash_proposal_list_info.key.ne(&slash_proposal_list_pubkey) {
+        msg!("SlashProposalList account is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    msg!(
+        "Initializing slash proposal list at address {}",
+        slash_proposal_list_info.key
+    );
+    create_account(
+        payer,
+        slash_proposal_list_info,
+        system_program,
+        program_id,
+        &Rent::get()?,
+        8_u64
+            .checked_add(std::mem::size_of::<SlashProposalList>() as u64)
+            .ok_or(ResolverError::ArithmeticOverflow)?,
+        &slash_proposal_list_seeds,
+    )?;
+
+    let mut slash_proposal_list_data = slash_proposal_list_info.try_borrow_mut_data()?;
+    slash_proposal_list_data[0] = SlashProposalList::DISCRIMINATOR;
+    let slash_proposal_list =
+        SlashProposalList::try_from_slice_unchecked_mut(&mut slash_proposal_list_data)?;
+    *slash_proposal_list =
+        SlashProposalList::new(*ncn_info.key, *operator_info.key, slash_proposal_list_bump);
+
+    Ok(())
+}
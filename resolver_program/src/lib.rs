@@ -1,15 +1,33 @@
+mod add_operator_to_blacklist;
+mod add_slash_destination;
+mod apply_resolver;
+mod approve_slash;
+mod cancel_resolver_change;
+mod contest_veto;
 mod delete_slash_proposal;
 mod execute_slash;
+mod get_config_status;
+mod get_slash_proposal_status;
 mod initialize_config;
 mod initialize_ncn_resolver_program_config;
+mod initialize_ncn_slash_stats;
 mod initialize_resolver;
+mod initialize_slash_proposal_list;
 mod initialize_slasher;
+mod migrate_slash_proposal;
 mod propose_slash;
+mod propose_slash_batch;
+mod propose_slash_bps;
+mod reassign_proposal_resolver;
+mod remove_operator_from_blacklist;
+mod remove_slash_destination;
+mod set_ncn_resolver_program_config_params;
 mod set_resolver;
 mod slasher_delegate_token_account;
 mod slasher_set_admin;
 mod slasher_set_secondary_admin;
 mod veto_slash;
+mod veto_slash_batch;
 
 use borsh::BorshDeserialize;
 use delete_slash_proposal::process_delete_slash_proposal;
@@ -20,15 +38,29 @@ use solana_program::{
 };
 
 use crate::{
-    execute_slash::process_execute_slash, initialize_config::process_initialize_config,
+    add_operator_to_blacklist::process_add_operator_to_blacklist,
+    add_slash_destination::process_add_slash_destination, apply_resolver::process_apply_resolver,
+    approve_slash::process_approve_slash, cancel_resolver_change::process_cancel_resolver_change,
+    contest_veto::process_contest_veto, execute_slash::process_execute_slash,
+    get_config_status::process_get_config_status,
+    get_slash_proposal_status::process_get_slash_proposal_status,
+    initialize_config::process_initialize_config,
     initialize_ncn_resolver_program_config::process_initialize_resolver_program_config,
+    initialize_ncn_slash_stats::process_initialize_ncn_slash_stats,
     initialize_resolver::process_initialize_resolver,
-    initialize_slasher::process_initialize_slasher, propose_slash::process_propose_slash,
+    initialize_slash_proposal_list::process_initialize_slash_proposal_list,
+    initialize_slasher::process_initialize_slasher,
+    migrate_slash_proposal::process_migrate_slash_proposal, propose_slash::process_propose_slash,
+    propose_slash_batch::process_propose_slash_batch, propose_slash_bps::process_propose_slash_bps,
+    reassign_proposal_resolver::process_reassign_proposal_resolver,
+    remove_operator_from_blacklist::process_remove_operator_from_blacklist,
+    remove_slash_destination::process_remove_slash_destination,
+    set_ncn_resolver_program_config_params::process_set_ncn_resolver_program_config_params,
     set_resolver::process_set_resolver,
     slasher_delegate_token_account::process_slasher_delegate_token_account,
     slasher_set_admin::process_slasher_set_admin,
     slasher_set_secondary_admin::process_slasher_set_secondary_admin,
-    veto_slash::process_veto_slash,
+    veto_slash::process_veto_slash, veto_slash_batch::process_veto_slash_batch,
 };
 
 declare_id!("AE7fSUJSGxMzjNxSPpNTemrz9cr26RFue4GwoJ1cuR6f");
@@ -76,9 +108,12 @@ pub fn process_instruction(
             process_initialize_slasher(program_id, accounts)?;
         }
 
-        ResolverInstruction::ProposeSlash { slash_amount } => {
+        ResolverInstruction::ProposeSlash {
+            slash_amount,
+            slash_destination,
+        } => {
             msg!("Instruction: ProposeSlash");
-            process_propose_slash(program_id, accounts, slash_amount)?;
+            process_propose_slash(program_id, accounts, slash_amount, slash_destination)?;
         }
 
         ResolverInstruction::SetResolver => {
@@ -86,9 +121,9 @@ pub fn process_instruction(
             process_set_resolver(program_id, accounts)?;
         }
 
-        ResolverInstruction::VetoSlash => {
+        ResolverInstruction::VetoSlash { reason } => {
             msg!("Instruction: VetoSlash");
-            process_veto_slash(program_id, accounts)?;
+            process_veto_slash(program_id, accounts, reason)?;
         }
 
         ResolverInstruction::ExecuteSlash => {
@@ -96,9 +131,11 @@ pub fn process_instruction(
             process_execute_slash(program_id, accounts)?;
         }
 
-        ResolverInstruction::SlasherDelegateTokenAccount => {
-            msg!("Instruction: ExecuteSlash");
-            process_slasher_delegate_token_account(program_id, accounts)?;
+        ResolverInstruction::SlasherDelegateTokenAccount {
+            max_delegated_amount,
+        } => {
+            msg!("Instruction: SlasherDelegateTokenAccount");
+            process_slasher_delegate_token_account(program_id, accounts, max_delegated_amount)?;
         }
 
         ResolverInstruction::SlasherSetAdmin => {
@@ -115,6 +152,115 @@ pub fn process_instruction(
             msg!("Instruction: DeleteSlashProposal");
             process_delete_slash_proposal(program_id, accounts)?;
         }
+
+        ResolverInstruction::InitializeSlashProposalList => {
+            msg!("Instruction: InitializeSlashProposalList");
+            process_initialize_slash_proposal_list(program_id, accounts)?;
+        }
+
+        ResolverInstruction::SetNcnResolverProgramConfigParams {
+            resolver_change_delay,
+            resolver_share_bps,
+            treasury,
+            veto_extension_slots,
+            max_veto_extensions,
+            approval_threshold,
+            execution_delay_slots,
+            re_veto_window_slots,
+        } => {
+            msg!("Instruction: SetNcnResolverProgramConfigParams");
+            process_set_ncn_resolver_program_config_params(
+                program_id,
+                accounts,
+                resolver_change_delay,
+                resolver_share_bps,
+                treasury,
+                veto_extension_slots,
+                max_veto_extensions,
+                approval_threshold,
+                execution_delay_slots,
+                re_veto_window_slots,
+            )?;
+        }
+
+        ResolverInstruction::ApplyResolver => {
+            msg!("Instruction: ApplyResolver");
+            process_apply_resolver(program_id, accounts)?;
+        }
+
+        ResolverInstruction::CancelResolverChange => {
+            msg!("Instruction: CancelResolverChange");
+            process_cancel_resolver_change(program_id, accounts)?;
+        }
+
+        ResolverInstruction::ContestVeto => {
+            msg!("Instruction: ContestVeto");
+            process_contest_veto(program_id, accounts)?;
+        }
+
+        ResolverInstruction::AddOperatorToBlacklist => {
+            msg!("Instruction: AddOperatorToBlacklist");
+            process_add_operator_to_blacklist(program_id, accounts)?;
+        }
+
+        ResolverInstruction::RemoveOperatorFromBlacklist => {
+            msg!("Instruction: RemoveOperatorFromBlacklist");
+            process_remove_operator_from_blacklist(program_id, accounts)?;
+        }
+
+        ResolverInstruction::ProposeSlashBps { slash_bps } => {
+            msg!("Instruction: ProposeSlashBps");
+            process_propose_slash_bps(program_id, accounts, slash_bps)?;
+        }
+
+        ResolverInstruction::ProposeSlashBatch {
+            slash_amount,
+            weights,
+        } => {
+            msg!("Instruction: ProposeSlashBatch");
+            process_propose_slash_batch(program_id, accounts, slash_amount, weights)?;
+        }
+
+        ResolverInstruction::GetConfigStatus => {
+            msg!("Instruction: GetConfigStatus");
+            process_get_config_status(program_id, accounts)?;
+        }
+
+        ResolverInstruction::ApproveSlash => {
+            msg!("Instruction: ApproveSlash");
+            process_approve_slash(program_id, accounts)?;
+        }
+
+        ResolverInstruction::VetoSlashBatch { reason } => {
+            msg!("Instruction: VetoSlashBatch");
+            process_veto_slash_batch(program_id, accounts, reason)?;
+        }
+
+        ResolverInstruction::GetSlashProposalStatus => {
+            msg!("Instruction: GetSlashProposalStatus");
+            process_get_slash_proposal_status(program_id, accounts)?;
+        }
+
+        ResolverInstruction::InitializeNcnSlashStats => {
+            msg!("Instruction: InitializeNcnSlashStats");
+            process_initialize_ncn_slash_stats(program_id, accounts)?;
+        }
+        ResolverInstruction::ReassignProposalResolver => {
+            msg!("Instruction: ReassignProposalResolver");
+            process_reassign_proposal_resolver(program_id, accounts)?;
+        }
+        ResolverInstruction::AddSlashDestination { slash_destination } => {
+            msg!("Instruction: AddSlashDestination");
+            process_add_slash_destination(program_id, accounts, slash_destination)?;
+        }
+        ResolverInstruction::RemoveSlashDestination { slash_destination } => {
+            msg!("Instruction: RemoveSlashDestination");
+            process_remove_slash_destination(program_id, accounts, slash_destination)?;
+        }
+        ResolverInstruction::MigrateSlashProposal => {
+            msg!("Instruction: MigrateSlashProposal");
+            process_migrate_slash_proposal(program_id, accounts)?;
+        }
     }
 
     Ok(())
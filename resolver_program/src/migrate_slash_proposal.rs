@@ -0,0 +1,125 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::{load_signer, load_system_program};
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use resolver_core::{
+    config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
+    slash_proposal::SlashProposal, slasher::Slasher,
+};
+use resolver_sdk::error::ResolverError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, system_instruction, sysvar::Sysvar,
+};
+
+/// Grows a slash proposal created with an older layout to the current size and stamps it with
+/// the current version. Fields added since the account was created are zero-initialized, except
+/// the approval threshold and execution delay, which are taken from the NCN's config so migrated
+/// proposals stay gated like new ones. The config admin tops up the rent for the extra space.
+/// Migrating an account that is already current is a no-op.
+pub fn process_migrate_slash_proposal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config_info, ncn_resolver_program_config_info, ncn_info, operator_info, slasher_info, slash_proposal_info, config_admin, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    NcnResolverProgramConfig::load(
+        program_id,
+        ncn_resolver_program_config_info,
+        ncn_info,
+        false,
+    )?;
+    let ncn_resolver_program_config_data = ncn_resolver_program_config_info.data.borrow();
+    let ncn_resolver_program_config =
+        NcnResolverProgramConfig::try_from_slice_unchecked(&ncn_resolver_program_config_data)?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+    Operator::load(&config.jito_restaking_program, operator_info, false)?;
+    Slasher::load(program_id, slasher_info, false)?;
+
+    // Only checks the owner, discriminator and PDA, so legacy-sized accounts load too
+    SlashProposal::load(
+        program_id,
+        slash_proposal_info,
+        ncn_info,
+        operator_info,
+        slasher_info,
+        true,
+    )?;
+
+    load_signer(config_admin, true)?;
+    load_system_program(system_program)?;
+    config.check_admin(config_admin.key)?;
+
+    let current_size = 8_usize
+        .checked_add(std::mem::size_of::<SlashProposal>())
+        .ok_or(ResolverError::ArithmeticOverflow)?;
+    let account_size = slash_proposal_info.data_len();
+    if account_size > current_size {
+        msg!(
+            "SlashProposal account size {} exceeds the current size {}",
+            account_size,
+            current_size
+        );
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if account_size < current_size {
+        let lamports_needed = Rent::get()?
+            .minimum_balance(current_size)
+            .saturating_sub(slash_proposal_info.lamports());
+        if lamports_needed > 0 {
+            invoke(
+                &system_instruction::transfer(
+                    config_admin.key,
+                    slash_proposal_info.key,
+                    lamports_needed,
+                ),
+                &[
+                    config_admin.clone(),
+                    slash_proposal_info.clone(),
+                    system_program.clone(),
+                ],
+            )?;
+        }
+
+        msg!(
+            "Reallocating slash proposal {} from {} to {} bytes",
+            slash_proposal_info.key,
+            account_size,
+            current_size
+        );
+        slash_proposal_info.realloc(current_size, true)?;
+    }
+
+    let mut slash_proposal_data = slash_proposal_info.data.borrow_mut();
+    let slash_proposal = SlashProposal::try_from_slice_unchecked_mut(&mut slash_proposal_data)?;
+
+    if slash_proposal.version() == SlashProposal::CURRENT_VERSION {
+        msg!(
+            "Slash proposal {} is already at version {}",
+            slash_proposal_info.key,
+            SlashProposal::CURRENT_VERSION
+        );
+        return Ok(());
+    }
+
+    msg!(
+        "Migrating slash proposal {} from version {} to {}",
+        slash_proposal_info.key,
+        slash_proposal.version(),
+        SlashProposal::CURRENT_VERSION
+    );
+    slash_proposal.set_approval_threshold(ncn_resolver_program_config.approval_threshold());
+    slash_proposal.set_execution_delay_slots(ncn_resolver_program_config.execution_delay_slots());
+    slash_proposal.set_version(SlashProposal::CURRENT_VERSION);
+
+    Ok(())
+}
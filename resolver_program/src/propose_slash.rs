@@ -1,26 +1,132 @@
+use borsh::BorshSerialize;
 use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
 };
-use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use jito_restaking_core::{
+    ncn::Ncn, ncn_vault_slasher_ticket::NcnVaultSlasherTicket, operator::Operator,
+};
+use jito_vault_core::{vault::Vault, vault_operator_delegation::VaultOperatorDelegation};
 use resolver_core::{
-    config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
-    ncn_slash_proposal_ticket::NcnSlashProposalTicket, slash_proposal::SlashProposal,
+    config::Config,
+    ncn_resolver_program_config::NcnResolverProgramConfig,
+    ncn_slash_proposal_ticket::NcnSlashProposalTicket,
+    ncn_slash_stats::NcnSlashStats,
+    slash_proposal::SlashProposal,
+    slash_proposal_list::{SlashProposalList, SlashProposalStatus},
     slasher::Slasher,
 };
-use resolver_sdk::error::ResolverError;
+use resolver_sdk::{error::ResolverError, return_data::ProposedSlash};
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    program::set_return_data, program_error::ProgramError, pubkey::Pubkey, rent::Rent,
+    sysvar::Sysvar,
 };
 
 pub fn process_propose_slash(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     slash_amount: u64,
+    slash_destination: Pubkey,
 ) -> ProgramResult {
-    let [config_info, ncn_resolver_program_config_info, ncn_info, operator_info, slasher_info, slash_proposal_info, ncn_slash_proposal_ticket_info, slasher_admin, system_program] =
+    let [config_info, _ncn_resolver_program_config_info, ncn_info, operator_info, slasher_info, _slash_proposal_info, _ncn_slash_proposal_ticket_info, _slash_proposal_list_info, _ncn_slash_stats_info, _slasher_admin, _system_program, vault_info, ncn_vault_slasher_ticket_info, vault_operator_delegation_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let delegated_stake = operator_delegated_stake(
+        program_id,
+        config_info,
+        ncn_info,
+        operator_info,
+        slasher_info,
+        vault_info,
+        ncn_vault_slasher_ticket_info,
+        vault_operator_delegation_info,
+    )?;
+
+    let proposed_slash = propose_slash(
+        program_id,
+        &accounts[..11],
+        slash_amount,
+        0,
+        Pubkey::default(),
+        slash_destination,
+        delegated_stake,
+    )?;
+
+    set_return_data(&proposed_slash.try_to_vec()?);
+
+    Ok(())
+}
+
+/// Returns the operator's stake delegated from the vault, which bounds the outstanding slash
+/// amount against the operator. The vault must have the slasher registered for the NCN, so a
+/// proposer can't size the slash off an unrelated vault.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn operator_delegated_stake(
+    program_id: &Pubkey,
+    config_info: &AccountInfo,
+    ncn_info: &AccountInfo,
+    operator_info: &AccountInfo,
+    slasher_info: &AccountInfo,
+    vault_info: &AccountInfo,
+    ncn_vault_slasher_ticket_info: &AccountInfo,
+    vault_operator_delegation_info: &AccountInfo,
+) -> Result<u64, ProgramError> {
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    Vault::load(&config.jito_vault_program, vault_info, false)?;
+    NcnVaultSlasherTicket::load(
+        &config.jito_restaking_program,
+        ncn_vault_slasher_ticket_info,
+        ncn_info,
+        vault_info,
+        slasher_info,
+        false,
+    )?;
+    VaultOperatorDelegation::load(
+        &config.jito_vault_program,
+        vault_operator_delegation_info,
+        vault_info,
+        operator_info,
+        false,
+    )?;
+    let vault_operator_delegation_data = vault_operator_delegation_info.data.borrow();
+    let vault_operator_delegation =
+        VaultOperatorDelegation::try_from_slice_unchecked(&vault_operator_delegation_data)?;
+
+    Ok(vault_operator_delegation
+        .delegation_state
+        .total_security()?)
+}
+
+/// Creates the slash proposal and its NCN ticket, shared by the absolute, bps-denominated and
+/// batch propose instructions
+///
+/// # Arguments
+/// * `slash_amount` - The absolute amount to slash
+/// * `slash_bps` - The bps the amount was derived from, 0 for absolute proposals
+/// * `batch_leader` - The first slash proposal of the batch, default for single proposals
+/// * `slash_destination` - Where the slashed funds are routed, default for the NCN's treasury
+/// * `delegated_stake` - The operator's delegated stake, see [`operator_delegated_stake`]
+///
+/// # Returns
+/// * `Result<ProposedSlash, ProgramError>` - The created slash proposal and its deadlines
+pub(crate) fn propose_slash(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    slash_amount: u64,
+    slash_bps: u16,
+    batch_leader: Pubkey,
+    slash_destination: Pubkey,
+    delegated_stake: u64,
+) -> Result<ProposedSlash, ProgramError> {
+    let [config_info, ncn_resolver_program_config_info, ncn_info, operator_info, slasher_info, slash_proposal_info, ncn_slash_proposal_ticket_info, slash_proposal_list_info, ncn_slash_stats_info, slasher_admin, system_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -49,12 +155,30 @@ pub fn process_propose_slash(
 
     load_system_account(slash_proposal_info, true)?;
     load_system_account(ncn_slash_proposal_ticket_info, true)?;
+
+    SlashProposalList::load(
+        program_id,
+        slash_proposal_list_info,
+        ncn_info,
+        operator_info,
+        true,
+    )?;
+    let mut slash_proposal_list_data = slash_proposal_list_info.data.borrow_mut();
+    let slash_proposal_list =
+        SlashProposalList::try_from_slice_unchecked_mut(&mut slash_proposal_list_data)?;
+
+    NcnSlashStats::load(program_id, ncn_slash_stats_info, ncn_info, true)?;
+    let mut ncn_slash_stats_data = ncn_slash_stats_info.data.borrow_mut();
+    let ncn_slash_stats = NcnSlashStats::try_from_slice_unchecked_mut(&mut ncn_slash_stats_data)?;
+
     load_signer(slasher_admin, true)?;
     load_system_program(system_program)?;
 
     let current_slot = Clock::get()?.slot;
 
-    slasher.check_admin(slasher_admin.key)?;
+    slasher.check_propose_admin(slasher_admin.key)?;
+    ncn_resolver_program_config.check_operator_not_blacklisted(operator_info.key)?;
+    slash_proposal_list.add_outstanding_slash_amount(slash_amount, delegated_stake)?;
 
     // Initialize SlashProposal
     {
@@ -97,8 +221,14 @@ pub fn process_propose_slash(
             slash_amount,
             current_slot,
             current_slot + ncn_resolver_program_config.veto_duration(),
+            slash_bps,
             slash_proposal_bump,
         );
+        slash_proposal.set_batch_leader(batch_leader);
+        slash_proposal.set_slash_destination(slash_destination);
+        slash_proposal.set_approval_threshold(ncn_resolver_program_config.approval_threshold());
+        slash_proposal
+            .set_execution_delay_slots(ncn_resolver_program_config.execution_delay_slots());
     }
 
     // Initialize NcnSlashProposalTicket
@@ -146,5 +276,12 @@ pub fn process_propose_slash(
         );
     }
 
-    Ok(())
+    slash_proposal_list.push(*slash_proposal_info.key, SlashProposalStatus::Proposed);
+    ncn_slash_stats.record_proposal(slash_amount);
+
+    Ok(ProposedSlash {
+        proposal: *slash_proposal_info.key,
+        capture_slot: current_slot,
+        veto_deadline_slot: current_slot + ncn_resolver_program_config.veto_duration(),
+    })
 }
@@ -0,0 +1,81 @@
+use resolver_core::slash_proposal::SlashProposal;
+use resolver_sdk::error::ResolverError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::propose_slash::{operator_delegated_stake, propose_slash};
+
+/// Proposes a slash against several operators at once, splitting `slash_amount` across them by
+/// weight. Each operator is passed as an (operator, slash_proposal, ncn_slash_proposal_ticket,
+/// slash_proposal_list, vault_operator_delegation) group after the fixed accounts.
+pub fn process_propose_slash_batch(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    slash_amount: u64,
+    weights: Vec<u64>,
+) -> ProgramResult {
+    let [config_info, ncn_resolver_program_config_info, ncn_info, slasher_info, slasher_admin, system_program, ncn_slash_stats_info, vault_info, ncn_vault_slasher_ticket_info, operator_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if weights.is_empty() || operator_accounts.len() != weights.len() * 5 {
+        msg!(
+            "Expected {} operator accounts for {} weights, got {}",
+            weights.len() * 5,
+            weights.len(),
+            operator_accounts.len()
+        );
+        return Err(ResolverError::SlashBatchInvalid.into());
+    }
+
+    let amounts = SlashProposal::split_batch_amount(slash_amount, &weights)?;
+
+    let batch_leader = *operator_accounts[1].key;
+    for (operator_group, amount) in operator_accounts.chunks_exact(5).zip(amounts) {
+        let [operator_info, slash_proposal_info, ncn_slash_proposal_ticket_info, slash_proposal_list_info, vault_operator_delegation_info] =
+            operator_group
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let delegated_stake = operator_delegated_stake(
+            program_id,
+            config_info,
+            ncn_info,
+            operator_info,
+            slasher_info,
+            vault_info,
+            ncn_vault_slasher_ticket_info,
+            vault_operator_delegation_info,
+        )?;
+
+        msg!("Proposing slash of {} for {}", amount, operator_info.key);
+        propose_slash(
+            program_id,
+            &[
+                config_info.clone(),
+                ncn_resolver_program_config_info.clone(),
+                ncn_info.clone(),
+                operator_info.clone(),
+                slasher_info.clone(),
+                slash_proposal_info.clone(),
+                ncn_slash_proposal_ticket_info.clone(),
+                slash_proposal_list_info.clone(),
+                ncn_slash_stats_info.clone(),
+                slasher_admin.clone(),
+                system_program.clone(),
+            ],
+            amount,
+            0,
+            batch_leader,
+            Pubkey::default(),
+            delegated_stake,
+        )?;
+    }
+
+    Ok(())
+}
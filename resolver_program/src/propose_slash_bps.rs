@@ -0,0 +1,69 @@
+use borsh::BorshSerialize;
+use resolver_core::ncn_resolver_program_config::NcnResolverProgramConfig;
+use resolver_sdk::error::ResolverError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::set_return_data,
+    program_error::ProgramError, pubkey::Pubkey,
+};
+
+use crate::propose_slash::{operator_delegated_stake, propose_slash};
+
+/// Proposes a slash of `slash_bps` of the operator's stake delegated from the vault, captured
+/// at proposal time
+pub fn process_propose_slash_bps(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    slash_bps: u16,
+) -> ProgramResult {
+    let [config_info, _ncn_resolver_program_config_info, ncn_info, operator_info, slasher_info, _slash_proposal_info, _ncn_slash_proposal_ticket_info, _slash_proposal_list_info, _ncn_slash_stats_info, _slasher_admin, _system_program, vault_info, ncn_vault_slasher_ticket_info, vault_operator_delegation_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if slash_bps > NcnResolverProgramConfig::MAX_BPS {
+        msg!(
+            "Slash bps {} exceeds {}",
+            slash_bps,
+            NcnResolverProgramConfig::MAX_BPS
+        );
+        return Err(ResolverError::SlashBpsInvalid.into());
+    }
+
+    let stake = operator_delegated_stake(
+        program_id,
+        config_info,
+        ncn_info,
+        operator_info,
+        slasher_info,
+        vault_info,
+        ncn_vault_slasher_ticket_info,
+        vault_operator_delegation_info,
+    )?;
+    let slash_amount: u64 = (stake as u128)
+        .checked_mul(slash_bps as u128)
+        .and_then(|product| product.checked_div(NcnResolverProgramConfig::MAX_BPS as u128))
+        .and_then(|quotient| quotient.try_into().ok())
+        .ok_or(ResolverError::ArithmeticOverflow)?;
+
+    msg!(
+        "Slashing {} bps of {} staked is {}",
+        slash_bps,
+        stake,
+        slash_amount
+    );
+
+    let proposed_slash = propose_slash(
+        program_id,
+        &accounts[..11],
+        slash_amount,
+        slash_bps,
+        Pubkey::default(),
+        Pubkey::default(),
+        stake,
+    )?;
+
+    set_return_data(&proposed_slash.try_to_vec()?);
+
+    Ok(())
+}
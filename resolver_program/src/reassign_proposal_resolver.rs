@@ -0,0 +1,80 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use resolver_core::{
+    config::Config, ncn_slash_proposal_ticket::NcnSlashProposalTicket, resolver::Resolver,
+    slash_proposal::SlashProposal, slasher::Slasher,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Replaces the resolver of an open slash proposal without waiting for the resolver change
+/// timelock, for when the assigned resolver is compromised or unavailable. Only the config
+/// admin can do this, and only while the proposal can still be vetoed.
+pub fn process_reassign_proposal_resolver(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config_info, ncn_info, operator_info, slasher_info, slash_proposal_info, ncn_slash_proposal_ticket_info, config_admin, new_resolver_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+    Operator::load(&config.jito_restaking_program, operator_info, false)?;
+    let operator_data = operator_info.data.borrow();
+    let operator = Operator::try_from_slice_unchecked(&operator_data)?;
+
+    Slasher::load(program_id, slasher_info, false)?;
+
+    SlashProposal::load(
+        program_id,
+        slash_proposal_info,
+        ncn_info,
+        operator_info,
+        slasher_info,
+        false,
+    )?;
+    let slash_proposal_data = slash_proposal_info.data.borrow();
+    let slash_proposal = SlashProposal::try_from_slice_unchecked(&slash_proposal_data)?;
+
+    NcnSlashProposalTicket::load(
+        program_id,
+        ncn_slash_proposal_ticket_info,
+        ncn_info,
+        slash_proposal_info,
+        true,
+    )?;
+    let mut ncn_slash_proposal_ticket_data = ncn_slash_proposal_ticket_info.data.borrow_mut();
+    let ncn_slash_proposal_ticket =
+        NcnSlashProposalTicket::try_from_slice_unchecked_mut(&mut ncn_slash_proposal_ticket_data)?;
+
+    load_signer(config_admin, false)?;
+    config.check_admin(config_admin.key)?;
+
+    slash_proposal.check_completed()?;
+    slash_proposal.check_veto_period_ended(Clock::get()?.slot)?;
+
+    Resolver::load(program_id, new_resolver_info, false)?;
+    let new_resolver_data = new_resolver_info.data.borrow();
+    let new_resolver = Resolver::try_from_slice_unchecked(&new_resolver_data)?;
+    new_resolver.check_ncn(ncn_info.key)?;
+    Resolver::check_not_operator(&new_resolver.admin, operator_info.key, &operator.admin)?;
+
+    msg!(
+        "Reassigning resolver of slash proposal {} from {} to {}",
+        slash_proposal_info.key,
+        ncn_slash_proposal_ticket.resolver,
+        new_resolver_info.key
+    );
+    ncn_slash_proposal_ticket.reassign_resolver(*new_resolver_info.key);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use resolver_core::{config::Config, ncn_resolver_program_config::NcnResolverProgramConfig};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Removes an operator from the NCN's blacklist
+pub fn process_remove_operator_from_blacklist(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config_info, ncn_resolver_program_config_info, ncn_info, operator_info, ncn_resolver_admin] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+    Operator::load(&config.jito_restaking_program, operator_info, false)?;
+    NcnResolverProgramConfig::load(program_id, ncn_resolver_program_config_info, ncn_info, true)?;
+    let mut ncn_resolver_program_config_data = ncn_resolver_program_config_info.data.borrow_mut();
+    let ncn_resolver_program_config = NcnResolverProgramConfig::try_from_slice_unchecked_mut(
+        &mut ncn_resolver_program_config_data,
+    )?;
+
+    load_signer(ncn_resolver_admin, false)?;
+
+    ncn_resolver_program_config.check_resolver_admin(ncn_resolver_admin.key)?;
+
+    ncn_resolver_program_config.remove_from_blacklist(operator_info.key)?;
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use resolver_core::{config::Config, ncn_resolver_program_config::NcnResolverProgramConfig};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Removes a destination from the NCN's slash destination allowlist
+pub fn process_remove_slash_destination(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    slash_destination: Pubkey,
+) -> ProgramResult {
+    let [config_info, ncn_resolver_program_config_info, ncn_info, ncn_resolver_admin] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+    NcnResolverProgramConfig::load(program_id, ncn_resolver_program_config_info, ncn_info, true)?;
+    let mut ncn_resolver_program_config_data = ncn_resolver_program_config_info.data.borrow_mut();
+    let ncn_resolver_program_config = NcnResolverProgramConfig::try_from_slice_unchecked_mut(
+        &mut ncn_resolver_program_config_data,
+    )?;
+
+    load_signer(ncn_resolver_admin, false)?;
+
+    ncn_resolver_program_config.check_resolver_admin(ncn_resolver_admin.key)?;
+
+    msg!("Removing slash destination {}", slash_destination);
+    ncn_resolver_program_config.remove_slash_destination(&slash_destination)?;
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use resolver_core::{config::Config, ncn_resolver_program_config::NcnResolverProgramConfig};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[allow(clippy::too_many_arguments)]
+pub fn process_set_ncn_resolver_program_config_params(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    resolver_change_delay: Option<u64>,
+    resolver_share_bps: Option<u16>,
+    treasury: Option<Pubkey>,
+    veto_extension_slots: Option<u64>,
+    max_veto_extensions: Option<u64>,
+    approval_threshold: Option<u64>,
+    execution_delay_slots: Option<u64>,
+    re_veto_window_slots: Option<u64>,
+) -> ProgramResult {
+    let [config_info, ncn_resolver_program_config_info, ncn_info, ncn_resolver_admin] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+    NcnResolverProgramConfig::load(program_id, ncn_resolver_program_config_info, ncn_info, true)?;
+    let mut ncn_resolver_program_config_data = ncn_resolver_program_config_info.data.borrow_mut();
+    let ncn_resolver_program_config = NcnResolverProgramConfig::try_from_slice_unchecked_mut(
+        &mut ncn_resolver_program_config_data,
+    )?;
+
+    load_signer(ncn_resolver_admin, false)?;
+
+    ncn_resolver_program_config.check_resolver_admin(ncn_resolver_admin.key)?;
+
+    if let Some(resolver_change_delay) = resolver_change_delay {
+        msg!("Setting resolver change delay to {}", resolver_change_delay);
+        ncn_resolver_program_config.set_resolver_change_delay(resolver_change_delay)?;
+    }
+
+    if let Some(resolver_share_bps) = resolver_share_bps {
+        msg!("Setting resolver share bps to {}", resolver_share_bps);
+        ncn_resolver_program_config.set_resolver_share_bps(resolver_share_bps)?;
+    }
+
+    if let Some(treasury) = treasury {
+        msg!("Setting treasury to {}", treasury);
+        ncn_resolver_program_config.treasury = treasury;
+    }
+
+    if let Some(veto_extension_slots) = veto_extension_slots {
+        msg!("Setting veto extension slots to {}", veto_extension_slots);
+        ncn_resolver_program_config.set_veto_extension_slots(veto_extension_slots)?;
+    }
+
+    if let Some(max_veto_extensions) = max_veto_extensions {
+        msg!("Setting max veto extensions to {}", max_veto_extensions);
+        ncn_resolver_program_config.set_max_veto_extensions(max_veto_extensions);
+    }
+
+    if let Some(approval_threshold) = approval_threshold {
+        msg!("Setting approval threshold to {}", approval_threshold);
+        ncn_resolver_program_config.set_approval_threshold(approval_threshold)?;
+    }
+
+    if let Some(execution_delay_slots) = execution_delay_slots {
+        msg!("Setting execution delay slots to {}", execution_delay_slots);
+        ncn_resolver_program_config.set_execution_delay_slots(execution_delay_slots)?;
+    }
+
+    if let Some(re_veto_window_slots) = re_veto_window_slots {
+        msg!("Setting re-veto window slots to {}", re_veto_window_slots);
+        ncn_resolver_program_config.set_re_veto_window_slots(re_veto_window_slots)?;
+    }
+
+    Ok(())
+}
@@ -3,12 +3,13 @@ use jito_jsm_core::loader::load_signer;
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use resolver_core::{
     config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
-    ncn_slash_proposal_ticket::NcnSlashProposalTicket, slash_proposal::SlashProposal,
-    slasher::Slasher,
+    ncn_slash_proposal_ticket::NcnSlashProposalTicket, resolver::Resolver,
+    slash_proposal::SlashProposal, slasher::Slasher,
 };
+use resolver_sdk::error::ResolverError;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
 pub fn process_set_resolver(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
@@ -34,6 +35,9 @@ pub fn process_set_resolver(program_id: &Pubkey, accounts: &[AccountInfo]) -> Pr
 
     Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
     Operator::load(&config.jito_restaking_program, operator_info, false)?;
+    let operator_data = operator_info.data.borrow();
+    let operator = Operator::try_from_slice_unchecked(&operator_data)?;
+
     Slasher::load(program_id, slasher_info, false)?;
 
     SlashProposal::load(
@@ -66,7 +70,30 @@ pub fn process_set_resolver(program_id: &Pubkey, accounts: &[AccountInfo]) -> Pr
         return Err(ProgramError::InvalidAccountData);
     }
 
-    ncn_slash_proposal_ticket.set_resolver(*new_resolver_info.key);
+    Resolver::load(program_id, new_resolver_info, false)?;
+    let new_resolver_data = new_resolver_info.data.borrow();
+    let new_resolver = Resolver::try_from_slice_unchecked(&new_resolver_data)?;
+    new_resolver.check_ncn(ncn_info.key)?;
+    Resolver::check_not_operator(&new_resolver.admin, operator_info.key, &operator.admin)?;
+
+    // Nothing can veto until a resolver is in place, so the first assignment isn't timelocked
+    if ncn_slash_proposal_ticket.resolver.eq(&Pubkey::default()) {
+        msg!("Resolver set to {}", new_resolver_info.key);
+        ncn_slash_proposal_ticket.set_resolver(*new_resolver_info.key);
+        return Ok(());
+    }
+
+    let effective_slot = Clock::get()?
+        .slot
+        .checked_add(ncn_resolver_program_config.resolver_change_delay())
+        .ok_or(ResolverError::ArithmeticOverflow)?;
+
+    msg!(
+        "Resolver change to {} is effective at slot {}",
+        new_resolver_info.key,
+        effective_slot
+    );
+    ncn_slash_proposal_ticket.set_pending_resolver(*new_resolver_info.key, effective_slot);
 
     Ok(())
 }
@@ -9,6 +9,7 @@ use solana_program::{
 pub fn process_slasher_delegate_token_account(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
+    max_delegated_amount: u64,
 ) -> ProgramResult {
     let [slasher_info, delegate_admin, token_mint, token_account, delegate, token_program_info] =
         accounts
@@ -49,7 +50,7 @@ pub fn process_slasher_delegate_token_account(
         delegate.key,
         slasher_info.key,
         &[],
-        u64::MAX,
+        max_delegated_amount,
     )?;
 
     invoke_signed(
@@ -3,7 +3,7 @@ use jito_jsm_core::loader::load_signer;
 use resolver_core::slasher::Slasher;
 use resolver_sdk::instruction::SlasherAdminRole;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     pubkey::Pubkey,
 };
 
@@ -27,6 +27,15 @@ pub fn process_slasher_set_secondary_admin(
     match role {
         SlasherAdminRole::DelegateAdmin => {
             slasher.delegate_admin = *new_admin.key;
+            msg!("Delegate admin set to {:?}", new_admin.key);
+        }
+        SlasherAdminRole::ProposeAdmin => {
+            slasher.propose_admin = *new_admin.key;
+            msg!("Propose admin set to {:?}", new_admin.key);
+        }
+        SlasherAdminRole::ExecuteAdmin => {
+            slasher.execute_admin = *new_admin.key;
+            msg!("Execute admin set to {:?}", new_admin.key);
         }
     }
 
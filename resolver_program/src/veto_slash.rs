@@ -2,17 +2,26 @@ use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::{load_signer, load_system_program};
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use resolver_core::{
-    config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
-    ncn_slash_proposal_ticket::NcnSlashProposalTicket, resolver::Resolver,
-    slash_proposal::SlashProposal, slasher::Slasher,
+    config::Config,
+    ncn_resolver_program_config::NcnResolverProgramConfig,
+    ncn_slash_proposal_ticket::NcnSlashProposalTicket,
+    ncn_slash_stats::NcnSlashStats,
+    resolver::Resolver,
+    slash_proposal::SlashProposal,
+    slash_proposal_list::{SlashProposalList, SlashProposalStatus},
+    slasher::Slasher,
 };
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
     program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
-pub fn process_veto_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let [config_info, ncn_resolver_program_config_info, ncn_info, operator_info, slasher_info, resolver_info, slash_proposal_info, ncn_slash_proposal_ticket_info, resolver_admin_info, system_program] =
+pub fn process_veto_slash(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    reason: [u8; 128],
+) -> ProgramResult {
+    let [config_info, ncn_resolver_program_config_info, ncn_info, operator_info, slasher_info, resolver_info, slash_proposal_info, ncn_slash_proposal_ticket_info, slash_proposal_list_info, ncn_slash_stats_info, resolver_admin_info, system_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -34,6 +43,9 @@ pub fn process_veto_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> Prog
 
     Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
     Operator::load(&config.jito_restaking_program, operator_info, false)?;
+    let operator_data = operator_info.data.borrow();
+    let operator = Operator::try_from_slice_unchecked(&operator_data)?;
+
     Slasher::load(program_id, slasher_info, false)?;
 
     Resolver::load(program_id, resolver_info, false)?;
@@ -62,10 +74,27 @@ pub fn process_veto_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> Prog
     let ncn_slash_proposal_ticket =
         NcnSlashProposalTicket::try_from_slice_unchecked(&ncn_slash_proposal_ticket_data)?;
 
+    SlashProposalList::load(
+        program_id,
+        slash_proposal_list_info,
+        ncn_info,
+        operator_info,
+        true,
+    )?;
+    let mut slash_proposal_list_data = slash_proposal_list_info.data.borrow_mut();
+    let slash_proposal_list =
+        SlashProposalList::try_from_slice_unchecked_mut(&mut slash_proposal_list_data)?;
+
+    NcnSlashStats::load(program_id, ncn_slash_stats_info, ncn_info, true)?;
+    let mut ncn_slash_stats_data = ncn_slash_stats_info.data.borrow_mut();
+    let ncn_slash_stats = NcnSlashStats::try_from_slice_unchecked_mut(&mut ncn_slash_stats_data)?;
+
     load_signer(resolver_admin_info, true)?;
     load_system_program(system_program)?;
 
     resolver.check_admin(resolver_admin_info.key)?;
+    resolver.check_ncn(ncn_info.key)?;
+    Resolver::check_not_operator(&resolver.admin, operator_info.key, &operator.admin)?;
 
     slash_proposal.check_veto_period_ended(Clock::get()?.slot)?;
     slash_proposal.check_completed()?;
@@ -74,10 +103,23 @@ pub fn process_veto_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> Prog
     ncn_slash_proposal_ticket.check_slash_proposal(slash_proposal_info.key)?;
 
     slash_proposal.set_completed(true);
+    slash_proposal.set_veto(*resolver_info.key, reason);
     slash_proposal.set_delete_deadline_slot(
         slash_proposal.delete_deadline_slot()
             + ncn_resolver_program_config.delete_slash_proposal_duration(),
     );
 
+    slash_proposal_list.update_status(*slash_proposal_info.key, SlashProposalStatus::Vetoed);
+    slash_proposal_list.release_outstanding_slash_amount(slash_proposal.amount());
+    ncn_slash_stats.record_veto();
+
+    let reason_len = reason.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+    msg!(
+        "Slash proposal {} vetoed by resolver {}: {}",
+        slash_proposal_info.key,
+        resolver_info.key,
+        String::from_utf8_lossy(&reason[..reason_len])
+    );
+
     Ok(())
 }
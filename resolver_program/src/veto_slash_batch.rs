@@ -0,0 +1,165 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use resolver_core::{
+    config::Config,
+    ncn_resolver_program_config::NcnResolverProgramConfig,
+    ncn_slash_proposal_ticket::NcnSlashProposalTicket,
+    ncn_slash_stats::NcnSlashStats,
+    resolver::Resolver,
+    slash_proposal::SlashProposal,
+    slash_proposal_list::{SlashProposalList, SlashProposalStatus},
+    slasher::Slasher,
+};
+use resolver_sdk::error::ResolverError;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::set_return_data, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Vetoes several slash proposals with the same reason. Each proposal is passed as an
+/// (operator, slasher, slash_proposal, ncn_slash_proposal_ticket, slash_proposal_list) group
+/// after the fixed accounts. Completed proposals and proposals outside their veto window are
+/// skipped, and the number of vetoed proposals is returned as a little-endian u64.
+pub fn process_veto_slash_batch(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    reason: [u8; 128],
+) -> ProgramResult {
+    let [config_info, ncn_resolver_program_config_info, ncn_info, resolver_info, resolver_admin_info, ncn_slash_stats_info, proposal_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if proposal_accounts.is_empty() || proposal_accounts.len() % 5 != 0 {
+        msg!(
+            "Expected groups of 5 slash proposal accounts, got {}",
+            proposal_accounts.len()
+        );
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    NcnResolverProgramConfig::load(
+        program_id,
+        ncn_resolver_program_config_info,
+        ncn_info,
+        false,
+    )?;
+    let ncn_resolver_program_config_data = ncn_resolver_program_config_info.data.borrow();
+    let ncn_resolver_program_config =
+        NcnResolverProgramConfig::try_from_slice_unchecked(&ncn_resolver_program_config_data)?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+
+    Resolver::load(program_id, resolver_info, false)?;
+    let resolver_data = resolver_info.data.borrow();
+    let resolver = Resolver::try_from_slice_unchecked(&resolver_data)?;
+
+    NcnSlashStats::load(program_id, ncn_slash_stats_info, ncn_info, true)?;
+    let mut ncn_slash_stats_data = ncn_slash_stats_info.data.borrow_mut();
+    let ncn_slash_stats = NcnSlashStats::try_from_slice_unchecked_mut(&mut ncn_slash_stats_data)?;
+
+    load_signer(resolver_admin_info, false)?;
+
+    resolver.check_admin(resolver_admin_info.key)?;
+    resolver.check_ncn(ncn_info.key)?;
+
+    let current_slot = Clock::get()?.slot;
+    let mut vetoed_count: u64 = 0;
+
+    for proposal_group in proposal_accounts.chunks_exact(5) {
+        let [operator_info, slasher_info, slash_proposal_info, ncn_slash_proposal_ticket_info, slash_proposal_list_info] =
+            proposal_group
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        Operator::load(&config.jito_restaking_program, operator_info, false)?;
+        let operator_data = operator_info.data.borrow();
+        let operator = Operator::try_from_slice_unchecked(&operator_data)?;
+        Resolver::check_not_operator(&resolver.admin, operator_info.key, &operator.admin)?;
+
+        Slasher::load(program_id, slasher_info, false)?;
+
+        SlashProposal::load(
+            program_id,
+            slash_proposal_info,
+            ncn_info,
+            operator_info,
+            slasher_info,
+            true,
+        )?;
+        let mut slash_proposal_data = slash_proposal_info.data.borrow_mut();
+        let slash_proposal = SlashProposal::try_from_slice_unchecked_mut(&mut slash_proposal_data)?;
+
+        NcnSlashProposalTicket::load(
+            program_id,
+            ncn_slash_proposal_ticket_info,
+            ncn_info,
+            slash_proposal_info,
+            false,
+        )?;
+        let ncn_slash_proposal_ticket_data = ncn_slash_proposal_ticket_info.data.borrow();
+        let ncn_slash_proposal_ticket =
+            NcnSlashProposalTicket::try_from_slice_unchecked(&ncn_slash_proposal_ticket_data)?;
+
+        SlashProposalList::load(
+            program_id,
+            slash_proposal_list_info,
+            ncn_info,
+            operator_info,
+            true,
+        )?;
+        let mut slash_proposal_list_data = slash_proposal_list_info.data.borrow_mut();
+        let slash_proposal_list =
+            SlashProposalList::try_from_slice_unchecked_mut(&mut slash_proposal_list_data)?;
+
+        ncn_slash_proposal_ticket.check_resolver(resolver_info.key)?;
+        ncn_slash_proposal_ticket.check_slash_proposal(slash_proposal_info.key)?;
+
+        if slash_proposal.completed() {
+            msg!(
+                "Skipping slash proposal {}, already completed",
+                slash_proposal_info.key
+            );
+            continue;
+        }
+        if slash_proposal.veto_deadline_slot() <= current_slot {
+            msg!(
+                "Skipping slash proposal {}, veto period ended",
+                slash_proposal_info.key
+            );
+            continue;
+        }
+
+        slash_proposal.set_completed(true);
+        slash_proposal.set_veto(*resolver_info.key, reason);
+        slash_proposal.set_delete_deadline_slot(
+            slash_proposal.delete_deadline_slot()
+                + ncn_resolver_program_config.delete_slash_proposal_duration(),
+        );
+
+        slash_proposal_list.update_status(*slash_proposal_info.key, SlashProposalStatus::Vetoed);
+        slash_proposal_list.release_outstanding_slash_amount(slash_proposal.amount());
+        ncn_slash_stats.record_veto();
+
+        msg!("Slash proposal {} vetoed", slash_proposal_info.key);
+        vetoed_count = vetoed_count
+            .checked_add(1)
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+    }
+
+    msg!(
+        "Vetoed {} of {} slash proposals",
+        vetoed_count,
+        proposal_accounts.len() / 5
+    );
+    set_return_data(&vetoed_count.to_le_bytes());
+
+    Ok(())
+}
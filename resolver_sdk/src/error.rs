@@ -1,34 +1,101 @@
 use solana_program::{decode_error::DecodeError, program_error::ProgramError};
 use thiserror::Error;
 
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
 pub enum ResolverError {
-    #[error("ResolverAdminInvalid")]
+    #[error("Resolver admin is invalid")]
     ResolverAdminInvalid,
 
-    #[error("SlasherAdminInvalid")]
+    #[error("Slasher admin is invalid")]
     SlasherAdminInvalid,
-    #[error("SlasherDelegateAdminInvalid")]
+    #[error("Slasher delegate admin is invalid")]
     SlasherDelegateAdminInvalid,
 
-    #[error("SlashProposalVetoPeriodEnded")]
+    #[error("Slash proposal veto period has ended")]
     SlashProposalVetoPeriodEnded,
-    #[error("SlashProposalVetoPeriodNotEnded")]
+    #[error("Slash proposal veto period has not ended")]
     SlashProposalVetoPeriodNotEnded,
-    #[error("SlashProposalCompleted")]
+    #[error("Slash proposal is already completed")]
     SlashProposalCompleted,
-    #[error("SlashProposalInvalid")]
+    #[error("Slash proposal is invalid")]
     SlashProposalInvalid,
-    #[error("SlashProposalResolverInvalid")]
+    #[error("Resolver is not assigned to the slash proposal")]
     SlashProposalResolverInvalid,
-    #[error("SlashProposalDeletePeriodNotEnded")]
+    #[error("Slash proposal delete period has not ended")]
     SlashProposalDeletePeriodNotEnded,
 
-    #[error("ArithmeticOverflow")]
+    #[error("NCN resolver program config admin is invalid")]
+    NcnResolverProgramConfigAdminInvalid,
+    #[error("No resolver change is pending")]
+    ResolverChangeNotPending,
+    #[error("Resolver change timelock has not elapsed")]
+    ResolverChangeTimelockNotElapsed,
+    #[error("Resolver share bps exceeds 10,000")]
+    ResolverShareBpsInvalid,
+    #[error("Slash proposal reached the maximum number of veto extensions")]
+    SlashProposalMaxVetoExtensionsReached,
+    #[error("Operator is blacklisted")]
+    OperatorBlacklisted,
+    #[error("Operator is already blacklisted")]
+    OperatorAlreadyBlacklisted,
+    #[error("Operator is not blacklisted")]
+    OperatorNotBlacklisted,
+    #[error("Operator blacklist is full")]
+    BlacklistFull,
+    #[error("Slash bps exceeds 10,000")]
+    SlashBpsInvalid,
+    #[error("Config is already initialized")]
+    ConfigAlreadyInitialized,
+    #[error("NCN resolver program config is already initialized")]
+    NcnResolverProgramConfigAlreadyInitialized,
+    #[error("Resolver is already initialized")]
+    ResolverAlreadyInitialized,
+    #[error("Slash batch is invalid")]
+    SlashBatchInvalid,
+    #[error("Approval threshold is invalid")]
+    ApprovalThresholdInvalid,
+    #[error("Slash proposal has not reached the approval threshold")]
+    ApprovalThresholdNotReached,
+    #[error("Signer is not an approver of the slash proposal")]
+    SlashApproverInvalid,
+    #[error("Slash proposal is already approved by this signer or has no approvals left")]
+    SlashProposalAlreadyApproved,
+    #[error("Execution delay after the veto deadline has not elapsed")]
+    ExecutionDelayNotElapsed,
+    #[error("Resolver belongs to a different NCN")]
+    ResolverNcnMismatch,
+    #[error("Slasher propose admin is invalid")]
+    SlasherProposeAdminInvalid,
+    #[error("Slasher execute admin is invalid")]
+    SlasherExecuteAdminInvalid,
+    #[error("Resolver is administered by the slashed operator")]
+    ResolverIsOperator,
+    #[error("Vault balance did not move by the slashed amount")]
+    SlashBalanceMismatch,
+    #[error("Config admin is invalid")]
+    ConfigAdminInvalid,
+    #[error("Slash destination is not allowed")]
+    InvalidSlashDestination,
+    #[error("Slash destination is already allowed")]
+    SlashDestinationAlreadyAllowed,
+    #[error("Slash destination allowlist is full")]
+    SlashDestinationAllowlistFull,
+    #[error("Outstanding slashes exceed the operator's delegated stake")]
+    OutstandingSlashExceedsStake,
+    #[error("Resolver change delay is below the minimum")]
+    ResolverChangeDelayTooShort,
+    #[error("Re-veto window is below the minimum")]
+    ReVetoWindowTooShort,
+    #[error("Execution delay is below the minimum")]
+    ExecutionDelayTooShort,
+    #[error("Veto extension is below the minimum")]
+    VetoExtensionTooShort,
+
+    #[error("Arithmetic overflow")]
     ArithmeticOverflow = 3000,
-    #[error("ArithmeticUnderflow")]
+    #[error("Arithmetic underflow")]
     ArithmeticUnderflow,
-    #[error("DivisionByZero")]
+    #[error("Division by zero")]
     DivisionByZero,
 }
 
@@ -49,3 +116,97 @@ impl From<ResolverError> for u64 {
         e as Self
     }
 }
+
+impl TryFrom<u32> for ResolverError {
+    type Error = ProgramError;
+
+    /// Maps a custom program error code back to its [`ResolverError`]
+    fn try_from(code: u32) -> Result<Self, Self::Error> {
+        match code {
+            x if x == Self::ResolverAdminInvalid as u32 => Ok(Self::ResolverAdminInvalid),
+            x if x == Self::SlasherAdminInvalid as u32 => Ok(Self::SlasherAdminInvalid),
+            x if x == Self::SlasherDelegateAdminInvalid as u32 => {
+                Ok(Self::SlasherDelegateAdminInvalid)
+            }
+            x if x == Self::SlashProposalVetoPeriodEnded as u32 => {
+                Ok(Self::SlashProposalVetoPeriodEnded)
+            }
+            x if x == Self::SlashProposalVetoPeriodNotEnded as u32 => {
+                Ok(Self::SlashProposalVetoPeriodNotEnded)
+            }
+            x if x == Self::SlashProposalCompleted as u32 => Ok(Self::SlashProposalCompleted),
+            x if x == Self::SlashProposalInvalid as u32 => Ok(Self::SlashProposalInvalid),
+            x if x == Self::SlashProposalResolverInvalid as u32 => {
+                Ok(Self::SlashProposalResolverInvalid)
+            }
+            x if x == Self::SlashProposalDeletePeriodNotEnded as u32 => {
+                Ok(Self::SlashProposalDeletePeriodNotEnded)
+            }
+            x if x == Self::NcnResolverProgramConfigAdminInvalid as u32 => {
+                Ok(Self::NcnResolverProgramConfigAdminInvalid)
+            }
+            x if x == Self::ResolverChangeNotPending as u32 => Ok(Self::ResolverChangeNotPending),
+            x if x == Self::ResolverChangeTimelockNotElapsed as u32 => {
+                Ok(Self::ResolverChangeTimelockNotElapsed)
+            }
+            x if x == Self::ResolverShareBpsInvalid as u32 => Ok(Self::ResolverShareBpsInvalid),
+            x if x == Self::SlashProposalMaxVetoExtensionsReached as u32 => {
+                Ok(Self::SlashProposalMaxVetoExtensionsReached)
+            }
+            x if x == Self::OperatorBlacklisted as u32 => Ok(Self::OperatorBlacklisted),
+            x if x == Self::OperatorAlreadyBlacklisted as u32 => {
+                Ok(Self::OperatorAlreadyBlacklisted)
+            }
+            x if x == Self::OperatorNotBlacklisted as u32 => Ok(Self::OperatorNotBlacklisted),
+            x if x == Self::BlacklistFull as u32 => Ok(Self::BlacklistFull),
+            x if x == Self::SlashBpsInvalid as u32 => Ok(Self::SlashBpsInvalid),
+            x if x == Self::ConfigAlreadyInitialized as u32 => Ok(Self::ConfigAlreadyInitialized),
+            x if x == Self::NcnResolverProgramConfigAlreadyInitialized as u32 => {
+                Ok(Self::NcnResolverProgramConfigAlreadyInitialized)
+            }
+            x if x == Self::ResolverAlreadyInitialized as u32 => {
+                Ok(Self::ResolverAlreadyInitialized)
+            }
+            x if x == Self::SlashBatchInvalid as u32 => Ok(Self::SlashBatchInvalid),
+            x if x == Self::ApprovalThresholdInvalid as u32 => Ok(Self::ApprovalThresholdInvalid),
+            x if x == Self::ApprovalThresholdNotReached as u32 => {
+                Ok(Self::ApprovalThresholdNotReached)
+            }
+            x if x == Self::SlashApproverInvalid as u32 => Ok(Self::SlashApproverInvalid),
+            x if x == Self::SlashProposalAlreadyApproved as u32 => {
+                Ok(Self::SlashProposalAlreadyApproved)
+            }
+            x if x == Self::ExecutionDelayNotElapsed as u32 => Ok(Self::ExecutionDelayNotElapsed),
+            x if x == Self::ResolverNcnMismatch as u32 => Ok(Self::ResolverNcnMismatch),
+            x if x == Self::SlasherProposeAdminInvalid as u32 => {
+                Ok(Self::SlasherProposeAdminInvalid)
+            }
+            x if x == Self::SlasherExecuteAdminInvalid as u32 => {
+                Ok(Self::SlasherExecuteAdminInvalid)
+            }
+            x if x == Self::ResolverIsOperator as u32 => Ok(Self::ResolverIsOperator),
+            x if x == Self::SlashBalanceMismatch as u32 => Ok(Self::SlashBalanceMismatch),
+            x if x == Self::ConfigAdminInvalid as u32 => Ok(Self::ConfigAdminInvalid),
+            x if x == Self::InvalidSlashDestination as u32 => Ok(Self::InvalidSlashDestination),
+            x if x == Self::SlashDestinationAlreadyAllowed as u32 => {
+                Ok(Self::SlashDestinationAlreadyAllowed)
+            }
+            x if x == Self::SlashDestinationAllowlistFull as u32 => {
+                Ok(Self::SlashDestinationAllowlistFull)
+            }
+            x if x == Self::OutstandingSlashExceedsStake as u32 => {
+                Ok(Self::OutstandingSlashExceedsStake)
+            }
+            x if x == Self::ResolverChangeDelayTooShort as u32 => {
+                Ok(Self::ResolverChangeDelayTooShort)
+            }
+            x if x == Self::ReVetoWindowTooShort as u32 => Ok(Self::ReVetoWindowTooShort),
+            x if x == Self::ExecutionDelayTooShort as u32 => Ok(Self::ExecutionDelayTooShort),
+            x if x == Self::VetoExtensionTooShort as u32 => Ok(Self::VetoExtensionTooShort),
+            x if x == Self::ArithmeticOverflow as u32 => Ok(Self::ArithmeticOverflow),
+            x if x == Self::ArithmeticUnderflow as u32 => Ok(Self::ArithmeticUnderflow),
+            x if x == Self::DivisionByZero as u32 => Ok(Self::DivisionByZero),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{msg, pubkey::Pubkey};
+
+use crate::error::ResolverError;
+
+/// Emitted through `sol_log_data` by `ExecuteSlash`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct SlashExecuted {
+    /// The executed slash proposal
+    pub proposal: Pubkey,
+
+    /// The slashed operator
+    pub operator: Pubkey,
+
+    /// The amount slashed
+    pub amount_slashed: u64,
+
+    /// The vault token account balance before the slash
+    pub vault_balance_before: u64,
+
+    /// The vault token account balance after the slash
+    pub vault_balance_after: u64,
+
+    /// The slot the slash was executed at
+    pub slot: u64,
+}
+
+impl SlashExecuted {
+    /// Creates the event, checking the vault balance moved by exactly `amount_slashed`
+    ///
+    /// # Errors
+    /// Returns [`ResolverError::SlashBalanceMismatch`] if
+    /// `vault_balance_before - vault_balance_after` differs from `amount_slashed`
+    pub fn new(
+        proposal: Pubkey,
+        operator: Pubkey,
+        amount_slashed: u64,
+        vault_balance_before: u64,
+        vault_balance_after: u64,
+        slot: u64,
+    ) -> Result<Self, ResolverError> {
+        if vault_balance_before.checked_sub(vault_balance_after) != Some(amount_slashed) {
+            msg!(
+                "Vault balance moved from {} to {}, expected a slash of {}",
+                vault_balance_before,
+                vault_balance_after,
+                amount_slashed
+            );
+            return Err(ResolverError::SlashBalanceMismatch);
+        }
+
+        Ok(Self {
+            proposal,
+            operator,
+            amount_slashed,
+            vault_balance_before,
+            vault_balance_after,
+            slot,
+        })
+    }
+}
@@ -1,5 +1,6 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankInstruction;
+use solana_program::pubkey::Pubkey;
 
 #[derive(Debug, BorshSerialize, BorshDeserialize, ShankInstruction)]
 pub enum ResolverInstruction {
@@ -26,6 +27,7 @@ pub enum ResolverInstruction {
     #[account(6, name = "system_program")]
     InitializeResolver,
 
+    /// Proposes a slash of an operator, returning a borsh-serialized `ProposedSlash` as return data
     #[account(0, name = "config")]
     #[account(1, name = "ncn_resolver_program_config")]
     #[account(2, name = "ncn")]
@@ -33,63 +35,90 @@ pub enum ResolverInstruction {
     #[account(4, name = "slasher")]
     #[account(5, writable, name = "slash_proposal")]
     #[account(6, writable, name = "ncn_slash_proposal_ticket")]
-    #[account(7, writable, signer, name = "slasher_admin")]
-    #[account(8, name = "system_program")]
+    #[account(7, writable, name = "slash_proposal_list")]
+    #[account(8, writable, name = "ncn_slash_stats")]
+    #[account(9, writable, signer, name = "slasher_admin")]
+    #[account(10, name = "system_program")]
+    #[account(11, name = "vault")]
+    #[account(12, name = "ncn_vault_slasher_ticket")]
+    #[account(13, name = "vault_operator_delegation")]
     ProposeSlash {
         slash_amount: u64,
+        /// The owner of the token account receiving the slashed funds, default for the NCN's
+        /// treasury. Checked against the NCN's slash destination allowlist at execution.
+        slash_destination: Pubkey,
     },
 
+    /// Sets the proposal's first resolver immediately, or schedules a change to an existing
+    /// resolver, effective after the NCN's resolver change delay
     #[account(0, name = "config")]
-    #[account(1, name = "ncn")]
-    #[account(2, name = "operator")]
-    #[account(3, name = "slasher")]
-    #[account(4, name = "slash_proposal")]
-    #[account(5, writable, name = "ncn_slash_proposal_ticket")]
-    #[account(6, signer, name = "ncn_slasher_admin")]
-    #[account(7, name = "new_resolver_info")]
-    SetResolver,
-
-    #[account(0, name = "config")]
-    #[account(1, name = "ncn")]
-    #[account(2, name = "operator")]
-    #[account(3, name = "slasher")]
-    #[account(4, name = "resolver")]
-    #[account(5, writable, name = "slash_proposal")]
+    #[account(1, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, name = "slasher")]
+    #[account(5, name = "slash_proposal")]
     #[account(6, writable, name = "ncn_slash_proposal_ticket")]
-    #[account(7, signer, name = "resolver_admin")]
-    #[account(8, name = "system_program")]
-    VetoSlash,
+    #[account(7, signer, name = "ncn_resolver_admin")]
+    #[account(8, name = "new_resolver")]
+    SetResolver,
 
     #[account(0, name = "config")]
-    #[account(1, name = "vault_config")]
+    #[account(1, name = "ncn_resolver_program_config")]
     #[account(2, name = "ncn")]
     #[account(3, name = "operator")]
     #[account(4, name = "slasher")]
-    #[account(5, writable, name = "vault")]
-    #[account(6, signer, name = "slasher_admin")]
-    #[account(7, name = "ncn_operator_state")]
-    #[account(8, name = "ncn_vault_ticket")]
-    #[account(9, name = "vault_ncn_ticket")]
-    #[account(10, writable, name = "vault_operator_delegation")]
-    #[account(11, name = "ncn_vault_slasher_ticket")]
-    #[account(12, name = "vault_ncn_slasker_ticket")]
-    #[account(13, writable, name = "vault_ncn_slasher_operator_ticket")]
-    #[account(14, writable, name = "vault_token_account")]
-    #[account(15, writable, name = "slasher_token_account")]
-    #[account(16, name = "resolver")]
-    #[account(17, writable, name = "slash_proposal")]
-    #[account(18, writable, name = "ncn_slash_proposal_ticket")]
-    #[account(19, name = "token_program")]
-    #[account(20, name = "jito_vault_program")]
+    #[account(5, name = "resolver")]
+    #[account(6, writable, name = "slash_proposal")]
+    #[account(7, writable, name = "ncn_slash_proposal_ticket")]
+    #[account(8, writable, name = "slash_proposal_list")]
+    #[account(9, writable, name = "ncn_slash_stats")]
+    #[account(10, signer, name = "resolver_admin")]
+    #[account(11, name = "system_program")]
+    VetoSlash {
+        /// The reason for the veto, zero-padded UTF-8
+        reason: [u8; 128],
+    },
+
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn_resolver_program_config")]
+    #[account(2, name = "vault_config")]
+    #[account(3, name = "ncn")]
+    #[account(4, name = "operator")]
+    #[account(5, name = "slasher")]
+    #[account(6, writable, name = "vault")]
+    #[account(7, signer, name = "slasher_admin")]
+    #[account(8, name = "ncn_operator_state")]
+    #[account(9, name = "ncn_vault_ticket")]
+    #[account(10, name = "operator_vault_ticket")]
+    #[account(11, name = "vault_ncn_ticket")]
+    #[account(12, writable, name = "vault_operator_delegation")]
+    #[account(13, name = "ncn_vault_slasher_ticket")]
+    #[account(14, name = "vault_ncn_slasher_ticket")]
+    #[account(15, writable, name = "vault_ncn_slasher_operator_ticket")]
+    #[account(16, writable, name = "vault_token_account")]
+    #[account(17, writable, name = "slasher_token_account")]
+    #[account(18, writable, name = "resolver_token_account")]
+    #[account(19, writable, name = "slash_destination_token_account")]
+    #[account(20, name = "resolver")]
+    #[account(21, writable, name = "slash_proposal")]
+    #[account(22, writable, name = "ncn_slash_proposal_ticket")]
+    #[account(23, writable, name = "slash_proposal_list")]
+    #[account(24, writable, name = "ncn_slash_stats")]
+    #[account(25, name = "token_program")]
+    #[account(26, name = "jito_vault_program")]
     ExecuteSlash,
 
+    /// Delegates the slasher's token account, up to `max_delegated_amount`. The token program
+    /// tracks the remaining allowance per token account.
     #[account(0, name = "slasher")]
     #[account(1, signer, name = "delegate_admin")]
     #[account(2, name = "token_mint")]
     #[account(3, writable, name = "token_account")]
     #[account(4, name = "delegate")]
     #[account(5, name = "token_program")]
-    SlasherDelegateTokenAccount,
+    SlasherDelegateTokenAccount {
+        max_delegated_amount: u64,
+    },
 
     /// Sets the admin for a slasher
     #[account(0, writable, name = "slasher")]
@@ -103,10 +132,223 @@ pub enum ResolverInstruction {
     #[account(2, name = "new_admin")]
     SlasherSetSecondaryAdmin(SlasherAdminRole),
 
+    /// Closes a slash proposal and its NCN ticket once the delete deadline has passed
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, name = "slasher")]
+    #[account(4, writable, name = "slash_proposal")]
+    #[account(5, writable, name = "ncn_slash_proposal_ticket")]
+    #[account(6, writable, name = "slash_proposal_list")]
+    #[account(7, writable, signer, name = "payer")]
+    #[account(8, name = "system_program")]
     DeleteSlashProposal,
+
+    /// Initializes the list tracking recent slash proposals against an operator
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, writable, name = "slash_proposal_list")]
+    #[account(4, writable, signer, name = "payer")]
+    #[account(5, name = "system_program")]
+    InitializeSlashProposalList,
+
+    /// Updates the parameters of a NCN resolver program config, fields left as `None` are unchanged
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, signer, name = "ncn_resolver_admin")]
+    SetNcnResolverProgramConfigParams {
+        resolver_change_delay: Option<u64>,
+        resolver_share_bps: Option<u16>,
+        treasury: Option<Pubkey>,
+        veto_extension_slots: Option<u64>,
+        max_veto_extensions: Option<u64>,
+        approval_threshold: Option<u64>,
+        execution_delay_slots: Option<u64>,
+        re_veto_window_slots: Option<u64>,
+    },
+
+    /// Applies a pending resolver change once its timelock has elapsed
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, name = "slasher")]
+    #[account(4, name = "slash_proposal")]
+    #[account(5, writable, name = "ncn_slash_proposal_ticket")]
+    ApplyResolver,
+
+    /// Cancels a pending resolver change
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, name = "slasher")]
+    #[account(5, name = "slash_proposal")]
+    #[account(6, writable, name = "ncn_slash_proposal_ticket")]
+    #[account(7, signer, name = "ncn_resolver_admin")]
+    CancelResolverChange,
+
+    /// Contests a slash proposal, extending its veto deadline
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, name = "slasher")]
+    #[account(5, writable, name = "slash_proposal")]
+    #[account(6, signer, name = "ncn_resolver_admin")]
+    ContestVeto,
+
+    /// Adds an operator to the NCN's blacklist, preventing slash proposals against it
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, signer, name = "ncn_resolver_admin")]
+    AddOperatorToBlacklist,
+
+    /// Removes an operator from the NCN's blacklist
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, signer, name = "ncn_resolver_admin")]
+    RemoveOperatorFromBlacklist,
+
+    /// Proposes a slash of a share of the operator's stake in a vault, in basis points, returning
+    /// a borsh-serialized `ProposedSlash` as return data
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, name = "slasher")]
+    #[account(5, writable, name = "slash_proposal")]
+    #[account(6, writable, name = "ncn_slash_proposal_ticket")]
+    #[account(7, writable, name = "slash_proposal_list")]
+    #[account(8, writable, name = "ncn_slash_stats")]
+    #[account(9, writable, signer, name = "slasher_admin")]
+    #[account(10, name = "system_program")]
+    #[account(11, name = "vault")]
+    #[account(12, name = "ncn_vault_slasher_ticket")]
+    #[account(13, name = "vault_operator_delegation")]
+    ProposeSlashBps {
+        slash_bps: u16,
+    },
+
+    /// Proposes a slash split across several operators by weight, followed by an
+    /// (operator, slash_proposal, ncn_slash_proposal_ticket, slash_proposal_list,
+    /// vault_operator_delegation) group per operator
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "slasher")]
+    #[account(4, writable, signer, name = "slasher_admin")]
+    #[account(5, name = "system_program")]
+    #[account(6, writable, name = "ncn_slash_stats")]
+    #[account(7, name = "vault")]
+    #[account(8, name = "ncn_vault_slasher_ticket")]
+    ProposeSlashBatch {
+        slash_amount: u64,
+        weights: Vec<u64>,
+    },
+
+    /// Returns a borsh-serialized `ConfigStatus` snapshot of the NCN's configuration as return data
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    GetConfigStatus,
+
+    /// Approves a slash proposal, counting towards the NCN's approval threshold
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, name = "slasher")]
+    #[account(5, writable, name = "slash_proposal")]
+    #[account(6, signer, name = "approver")]
+    ApproveSlash,
+
+    /// Vetoes several slash proposals, followed by an (operator, slasher, slash_proposal,
+    /// ncn_slash_proposal_ticket, slash_proposal_list) group per proposal. Returns the number
+    /// of vetoed proposals as return data.
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "resolver")]
+    #[account(4, signer, name = "resolver_admin")]
+    #[account(5, writable, name = "ncn_slash_stats")]
+    VetoSlashBatch {
+        /// The reason for the veto, zero-padded UTF-8
+        reason: [u8; 128],
+    },
+
+    /// Returns a borsh-serialized `SlashProposalStatusData` snapshot of a slash proposal,
+    /// including its state at the current slot, as return data
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, name = "slasher")]
+    #[account(4, name = "slash_proposal")]
+    GetSlashProposalStatus,
+
+    /// Initializes the aggregate slashing statistics of a NCN
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "ncn_slash_stats")]
+    #[account(3, writable, signer, name = "payer")]
+    #[account(4, name = "system_program")]
+    InitializeNcnSlashStats,
+
+    /// Replaces the resolver of an open slash proposal immediately, bypassing the resolver
+    /// change timelock
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, name = "slasher")]
+    #[account(4, name = "slash_proposal")]
+    #[account(5, writable, name = "ncn_slash_proposal_ticket")]
+    #[account(6, signer, name = "config_admin")]
+    #[account(7, name = "new_resolver")]
+    ReassignProposalResolver,
+
+    /// Adds a destination to the NCN's slash destination allowlist
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, signer, name = "ncn_resolver_admin")]
+    AddSlashDestination {
+        /// The owner of the token account receiving slashed funds
+        slash_destination: Pubkey,
+    },
+
+    /// Removes a destination from the NCN's slash destination allowlist
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, signer, name = "ncn_resolver_admin")]
+    RemoveSlashDestination {
+        /// The owner of the token account receiving slashed funds
+        slash_destination: Pubkey,
+    },
+
+    /// Grows a slash proposal created with an older layout to the current size
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, name = "slasher")]
+    #[account(5, writable, name = "slash_proposal")]
+    #[account(6, writable, signer, name = "config_admin")]
+    #[account(7, name = "system_program")]
+    MigrateSlashProposal,
 }
 
 #[derive(Debug, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
 pub enum SlasherAdminRole {
+    /// Can delegate the slasher's token accounts
     DelegateAdmin,
+    /// Can propose slashes on behalf of the slasher
+    ProposeAdmin,
+    /// Can execute the slasher's slash proposals
+    ExecuteAdmin,
 }
@@ -1,3 +1,5 @@
 pub mod error;
+pub mod event;
 pub mod instruction;
+pub mod return_data;
 pub mod sdk;
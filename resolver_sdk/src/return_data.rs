@@ -0,0 +1,106 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+/// Snapshot of an NCN's resolver program configuration, returned by `GetConfigStatus`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ConfigStatus {
+    /// The length of an epoch in slots
+    pub epoch_length: u64,
+
+    /// The resolver admin of the NCN
+    pub resolver_admin: Pubkey,
+
+    /// The number of slots a slash proposal can be vetoed for
+    pub veto_duration: u64,
+
+    /// The number of slots after completion before a slash proposal can be deleted
+    pub delete_slash_proposal_duration: u64,
+
+    /// The number of resolvers registered with the NCN
+    pub resolver_count: u64,
+
+    /// The number of slots a resolver change waits before it can be applied
+    pub resolver_change_delay: u64,
+
+    /// The owner of the token account receiving the treasury's share of slashed funds
+    pub treasury: Pubkey,
+
+    /// The share of slashed funds paid to the resolver, in basis points
+    pub resolver_share_bps: u16,
+
+    /// The number of slots a contested veto extends the veto deadline by
+    pub veto_extension_slots: u64,
+
+    /// The maximum number of times a slash proposal's veto deadline can be extended
+    pub max_veto_extensions: u64,
+
+    /// The number of blacklisted operators
+    pub blacklist_len: u64,
+
+    /// The number of approvals a slash proposal needs before it can be executed
+    pub approval_threshold: u64,
+
+    /// The number of slots after the veto deadline before a slash can be executed
+    pub execution_delay_slots: u64,
+
+    /// The number of slots the veto window reopens for after an execution attempt that can't
+    /// slash
+    pub re_veto_window_slots: u64,
+
+    /// The number of allowed slash destinations
+    pub slash_destinations_len: u64,
+}
+
+/// Snapshot of a slash proposal, returned by `GetSlashProposalStatus`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct SlashProposalStatusData {
+    /// The operator account
+    pub operator: Pubkey,
+
+    /// The slasher account
+    pub slasher: Pubkey,
+
+    /// The slash amount
+    pub amount: u64,
+
+    /// The slot the slash proposal was captured at
+    pub capture_slot: u64,
+
+    /// The last slot the slash proposal can be vetoed at, exclusive
+    pub veto_deadline_slot: u64,
+
+    /// The first slot the slash can be executed at
+    pub executable_after_slot: u64,
+
+    /// The first slot the slash proposal can be deleted at once completed
+    pub delete_deadline_slot: u64,
+
+    /// The number of approvals recorded
+    pub approvals_count: u64,
+
+    /// The number of approvals needed before execution
+    pub approval_threshold: u64,
+
+    /// The `SlashProposalState` of the slash proposal at the current slot
+    pub state: u8,
+}
+
+/// The slash proposal created by `ProposeSlash` or `ProposeSlashBps`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ProposedSlash {
+    /// The slash proposal PDA
+    pub proposal: Pubkey,
+
+    /// The slot the slash proposal was captured at
+    pub capture_slot: u64,
+
+    /// The last slot the slash proposal can be vetoed at, exclusive
+    pub veto_deadline_slot: u64,
+}
+
+impl ProposedSlash {
+    /// Decodes the return data of a `ProposeSlash` or `ProposeSlashBps` transaction
+    pub fn from_return_data(data: &[u8]) -> std::io::Result<Self> {
+        Self::try_from_slice(data)
+    }
+}
@@ -121,8 +121,14 @@ pub fn propose_slash(
     slasher: &Pubkey,
     slash_proposal: &Pubkey,
     ncn_slash_proposal_ticket: &Pubkey,
+    slash_proposal_list: &Pubkey,
+    ncn_slash_stats: &Pubkey,
     slasher_admin: &Pubkey,
+    vault: &Pubkey,
+    ncn_vault_slasher_ticket: &Pubkey,
+    vault_operator_delegation: &Pubkey,
     slash_amount: u64,
+    slash_destination: Pubkey,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*config, false),
@@ -132,16 +138,24 @@ pub fn propose_slash(
         AccountMeta::new_readonly(*slasher, false),
         AccountMeta::new(*slash_proposal, false),
         AccountMeta::new(*ncn_slash_proposal_ticket, false),
+        AccountMeta::new(*slash_proposal_list, false),
+        AccountMeta::new(*ncn_slash_stats, false),
         AccountMeta::new(*slasher_admin, true),
         AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*ncn_vault_slasher_ticket, false),
+        AccountMeta::new_readonly(*vault_operator_delegation, false),
     ];
 
     Instruction {
         program_id: *program_id,
         accounts,
-        data: ResolverInstruction::ProposeSlash { slash_amount }
-            .try_to_vec()
-            .unwrap(),
+        data: ResolverInstruction::ProposeSlash {
+            slash_amount,
+            slash_destination,
+        }
+        .try_to_vec()
+        .unwrap(),
     }
 }
 
@@ -188,7 +202,10 @@ pub fn veto_slash(
     resolver: &Pubkey,
     slash_proposal: &Pubkey,
     ncn_slash_proposal_ticket: &Pubkey,
+    slash_proposal_list: &Pubkey,
+    ncn_slash_stats: &Pubkey,
     resolver_admin: &Pubkey,
+    reason: [u8; 128],
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*config, false),
@@ -199,6 +216,8 @@ pub fn veto_slash(
         AccountMeta::new_readonly(*resolver, false),
         AccountMeta::new(*slash_proposal, false),
         AccountMeta::new(*ncn_slash_proposal_ticket, false),
+        AccountMeta::new(*slash_proposal_list, false),
+        AccountMeta::new(*ncn_slash_stats, false),
         AccountMeta::new(*resolver_admin, true),
         AccountMeta::new_readonly(system_program::id(), false),
     ];
@@ -206,7 +225,9 @@ pub fn veto_slash(
     Instruction {
         program_id: *program_id,
         accounts,
-        data: ResolverInstruction::VetoSlash.try_to_vec().unwrap(),
+        data: ResolverInstruction::VetoSlash { reason }
+            .try_to_vec()
+            .unwrap(),
     }
 }
 
@@ -231,9 +252,13 @@ pub fn execute_slash(
     vault_ncn_slasher_operator_ticket: &Pubkey,
     vault_token_account: &Pubkey,
     slasher_token_account: &Pubkey,
+    resolver_token_account: &Pubkey,
+    slash_destination_token_account: &Pubkey,
     resolver: &Pubkey,
     slash_proposal: &Pubkey,
     ncn_slash_proposal_ticket: &Pubkey,
+    slash_proposal_list: &Pubkey,
+    ncn_slash_stats: &Pubkey,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*config, false),
@@ -254,9 +279,13 @@ pub fn execute_slash(
         AccountMeta::new(*vault_ncn_slasher_operator_ticket, false),
         AccountMeta::new(*vault_token_account, false),
         AccountMeta::new(*slasher_token_account, false),
+        AccountMeta::new(*resolver_token_account, false),
+        AccountMeta::new(*slash_destination_token_account, false),
         AccountMeta::new_readonly(*resolver, false),
         AccountMeta::new(*slash_proposal, false),
         AccountMeta::new(*ncn_slash_proposal_ticket, false),
+        AccountMeta::new(*slash_proposal_list, false),
+        AccountMeta::new(*ncn_slash_stats, false),
         AccountMeta::new_readonly(spl_token::id(), false),
         AccountMeta::new_readonly(jito_vault_program::id(), false),
     ];
@@ -276,6 +305,7 @@ pub fn slasher_delegate_token_account(
     token_account: &Pubkey,
     delegate: &Pubkey,
     token_program: &Pubkey,
+    max_delegated_amount: u64,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*slasher, false),
@@ -288,9 +318,11 @@ pub fn slasher_delegate_token_account(
     Instruction {
         program_id: *program_id,
         accounts,
-        data: ResolverInstruction::SlasherDelegateTokenAccount
-            .try_to_vec()
-            .unwrap(),
+        data: ResolverInstruction::SlasherDelegateTokenAccount {
+            max_delegated_amount,
+        }
+        .try_to_vec()
+        .unwrap(),
     }
 }
 
@@ -342,6 +374,7 @@ pub fn delete_slash_proposal(
     slasher: &Pubkey,
     slash_proposal: &Pubkey,
     ncn_slash_proposal_ticket: &Pubkey,
+    slash_proposal_list: &Pubkey,
     payer: &Pubkey,
 ) -> Instruction {
     let accounts = vec![
@@ -351,6 +384,7 @@ pub fn delete_slash_proposal(
         AccountMeta::new_readonly(*slasher, false),
         AccountMeta::new(*slash_proposal, false),
         AccountMeta::new(*ncn_slash_proposal_ticket, false),
+        AccountMeta::new(*slash_proposal_list, false),
         AccountMeta::new(*payer, true),
         AccountMeta::new_readonly(system_program::id(), false),
     ];
@@ -362,3 +396,551 @@ pub fn delete_slash_proposal(
             .unwrap(),
     }
 }
+
+pub fn initialize_slash_proposal_list(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    slash_proposal_list: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*slash_proposal_list, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::InitializeSlashProposalList
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn set_ncn_resolver_program_config_params(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    ncn_resolver_admin: &Pubkey,
+    resolver_change_delay: Option<u64>,
+    resolver_share_bps: Option<u16>,
+    treasury: Option<Pubkey>,
+    veto_extension_slots: Option<u64>,
+    max_veto_extensions: Option<u64>,
+    approval_threshold: Option<u64>,
+    execution_delay_slots: Option<u64>,
+    re_veto_window_slots: Option<u64>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*ncn_resolver_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::SetNcnResolverProgramConfigParams {
+            resolver_change_delay,
+            resolver_share_bps,
+            treasury,
+            veto_extension_slots,
+            max_veto_extensions,
+            approval_threshold,
+            execution_delay_slots,
+            re_veto_window_slots,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn apply_resolver(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    slash_proposal: &Pubkey,
+    ncn_slash_proposal_ticket: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new_readonly(*slash_proposal, false),
+        AccountMeta::new(*ncn_slash_proposal_ticket, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::ApplyResolver.try_to_vec().unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn cancel_resolver_change(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    slash_proposal: &Pubkey,
+    ncn_slash_proposal_ticket: &Pubkey,
+    ncn_resolver_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new_readonly(*slash_proposal, false),
+        AccountMeta::new(*ncn_slash_proposal_ticket, false),
+        AccountMeta::new_readonly(*ncn_resolver_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::CancelResolverChange
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn contest_veto(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    slash_proposal: &Pubkey,
+    ncn_resolver_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new(*slash_proposal, false),
+        AccountMeta::new_readonly(*ncn_resolver_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::ContestVeto.try_to_vec().unwrap(),
+    }
+}
+
+pub fn add_operator_to_blacklist(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    ncn_resolver_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*ncn_resolver_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::AddOperatorToBlacklist
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn remove_operator_from_blacklist(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    ncn_resolver_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*ncn_resolver_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::RemoveOperatorFromBlacklist
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn propose_slash_bps(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    slash_proposal: &Pubkey,
+    ncn_slash_proposal_ticket: &Pubkey,
+    slash_proposal_list: &Pubkey,
+    ncn_slash_stats: &Pubkey,
+    slasher_admin: &Pubkey,
+    vault: &Pubkey,
+    ncn_vault_slasher_ticket: &Pubkey,
+    vault_operator_delegation: &Pubkey,
+    slash_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new(*slash_proposal, false),
+        AccountMeta::new(*ncn_slash_proposal_ticket, false),
+        AccountMeta::new(*slash_proposal_list, false),
+        AccountMeta::new(*ncn_slash_stats, false),
+        AccountMeta::new(*slasher_admin, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*ncn_vault_slasher_ticket, false),
+        AccountMeta::new_readonly(*vault_operator_delegation, false),
+    ];
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::ProposeSlashBps { slash_bps }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// An operator's accounts and weight in a batch slash proposal
+pub struct BatchSlashOperator {
+    pub operator: Pubkey,
+    pub slash_proposal: Pubkey,
+    pub ncn_slash_proposal_ticket: Pubkey,
+    pub slash_proposal_list: Pubkey,
+    pub vault_operator_delegation: Pubkey,
+    pub weight: u64,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn propose_slash_batch(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    slasher: &Pubkey,
+    slasher_admin: &Pubkey,
+    ncn_slash_stats: &Pubkey,
+    vault: &Pubkey,
+    ncn_vault_slasher_ticket: &Pubkey,
+    operators: &[BatchSlashOperator],
+    slash_amount: u64,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new(*slasher_admin, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(*ncn_slash_stats, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*ncn_vault_slasher_ticket, false),
+    ];
+    for operator in operators {
+        accounts.push(AccountMeta::new_readonly(operator.operator, false));
+        accounts.push(AccountMeta::new(operator.slash_proposal, false));
+        accounts.push(AccountMeta::new(operator.ncn_slash_proposal_ticket, false));
+        accounts.push(AccountMeta::new(operator.slash_proposal_list, false));
+        accounts.push(AccountMeta::new_readonly(
+            operator.vault_operator_delegation,
+            false,
+        ));
+    }
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::ProposeSlashBatch {
+            slash_amount,
+            weights: operators.iter().map(|operator| operator.weight).collect(),
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn get_config_status(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+    ];
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::GetConfigStatus.try_to_vec().unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn approve_slash(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    slash_proposal: &Pubkey,
+    approver: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new(*slash_proposal, false),
+        AccountMeta::new_readonly(*approver, true),
+    ];
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::ApproveSlash.try_to_vec().unwrap(),
+    }
+}
+
+/// A slash proposal's accounts in a batch veto
+pub struct BatchVetoProposal {
+    pub operator: Pubkey,
+    pub slasher: Pubkey,
+    pub slash_proposal: Pubkey,
+    pub ncn_slash_proposal_ticket: Pubkey,
+    pub slash_proposal_list: Pubkey,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn veto_slash_batch(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    resolver: &Pubkey,
+    resolver_admin: &Pubkey,
+    ncn_slash_stats: &Pubkey,
+    proposals: &[BatchVetoProposal],
+    reason: [u8; 128],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*resolver, false),
+        AccountMeta::new_readonly(*resolver_admin, true),
+        AccountMeta::new(*ncn_slash_stats, false),
+    ];
+    for proposal in proposals {
+        accounts.push(AccountMeta::new_readonly(proposal.operator, false));
+        accounts.push(AccountMeta::new_readonly(proposal.slasher, false));
+        accounts.push(AccountMeta::new(proposal.slash_proposal, false));
+        accounts.push(AccountMeta::new_readonly(
+            proposal.ncn_slash_proposal_ticket,
+            false,
+        ));
+        accounts.push(AccountMeta::new(proposal.slash_proposal_list, false));
+    }
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::VetoSlashBatch { reason }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn get_slash_proposal_status(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    slash_proposal: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new_readonly(*slash_proposal, false),
+    ];
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::GetSlashProposalStatus
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn initialize_ncn_slash_stats(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    ncn_slash_stats: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*ncn_slash_stats, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::InitializeNcnSlashStats
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn reassign_proposal_resolver(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    slash_proposal: &Pubkey,
+    ncn_slash_proposal_ticket: &Pubkey,
+    config_admin: &Pubkey,
+    new_resolver: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new_readonly(*slash_proposal, false),
+        AccountMeta::new(*ncn_slash_proposal_ticket, false),
+        AccountMeta::new_readonly(*config_admin, true),
+        AccountMeta::new_readonly(*new_resolver, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::ReassignProposalResolver
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn add_slash_destination(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    ncn_resolver_admin: &Pubkey,
+    slash_destination: Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*ncn_resolver_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::AddSlashDestination { slash_destination }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn remove_slash_destination(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    ncn_resolver_admin: &Pubkey,
+    slash_destination: Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*ncn_resolver_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::RemoveSlashDestination { slash_destination }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn migrate_slash_proposal(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    slash_proposal: &Pubkey,
+    config_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new(*slash_proposal, false),
+        AccountMeta::new(*config_admin, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::MigrateSlashProposal
+            .try_to_vec()
+            .unwrap(),
+    }
+}